@@ -0,0 +1,1132 @@
+use std::collections::BTreeMap;
+use std::io::{IsTerminal, Read};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode, Stdio};
+use std::thread;
+use std::time::Duration;
+
+use aoc2024::animate::Animation;
+use aoc2024::answers::{self, Answers};
+use aoc2024::bench::{self, BenchConfig};
+use aoc2024::days::{self, DAYS};
+use aoc2024::error::AocError;
+use aoc2024::history::{self, History};
+use aoc2024::journal::{self, Feedback, Journal};
+use aoc2024::memory;
+use aoc2024::render::{self, Image, Palette};
+use aoc2024::rng::Rng;
+use aoc2024::runner::{self, DayReport, Format};
+use aoc2024::watch::{self, Snapshot};
+use aoc2024::{input, scaffold, Part, Puzzle};
+
+const USAGE: &str = "\
+Usage:
+    aoc <DAY> [PART] [--input <PATH>]    Run a day, or one part of it
+    aoc --all [DAY]... [--jobs <N>]      Run all registered days, or the given days, concurrently
+    aoc bench [DAY]...                   Benchmark days, all days if none given
+    aoc verify [DAY]...                  Verify days against accepted answers
+    aoc accept <DAY> <PART> <ANSWER>     Record the accepted answer of a part
+    aoc guess <DAY> <PART> <ANSWER> <VERDICT>
+                                         Record a submitted answer, VERDICT is `correct`, `high`,
+                                         `low` or `wrong`
+    aoc render <DAY> [--steps <RANGE>]   Write frames of a simulation as images
+    aoc animate <DAY> [--steps <RANGE>]  Play a simulation in the terminal
+    aoc new <DAY> [--title <TITLE>]      Create files of a new day and register it
+    aoc gen <DAY> [--size <N>]           Print a random input of a day
+    aoc watch <DAY> [--release]          Rebuild, test and run a day whenever its files change
+
+Options:
+    --input <PATH>        Read input from PATH instead of the data directory, `-` for stdin
+    --jobs <N>            Run all: worker threads (default one per core)
+    --format <FORMAT>     Run and benchmark: `table` (default), `json` or `csv`
+    --memory-budget <SIZE>
+                          Run: fail parts with a heap peak over SIZE, e.g. `512M` or `2G`,
+                          needs feature `count-alloc`. Enforcement is cooperative: checked when
+                          a part returns or calls `check_budget`, a part that does neither is
+                          not stopped
+    --warmup <N>          Benchmark: runs before measuring (default 3)
+    --iterations <N>      Benchmark: measured runs (default 20)
+    --generate <SIZE>     Benchmark: run on random inputs of SIZE instead of the data directory
+    --size <N>            Gen: size of the input, e.g. lines or the side of a map (default 100)
+    --seed <N>            Gen and benchmark: seed of random inputs (default 2024)
+    --label <NAME>        Benchmark: name the run in the history, to compare against it later
+    --compare             Benchmark: flag parts slower than in the previous run of the same input
+    --baseline <NAME>     Benchmark: compare against the run named NAME instead
+    --threshold <PERCENT> Benchmark: slowdown flagged by compare (default 10)
+    --update-readme       Verify: rewrite the status table in README.md
+    --interval <MS>       Watch: milliseconds between polls of modification times (default 500)
+    --release             Watch: build with optimizations
+    --steps <RANGE>       Render: steps to write, `N` or `A..B` (default 0)
+    --scale <N>           Render: pixels per tile (default 4)
+    --out <DIR>           Render: output directory (default frames/<DAY>)
+    --gray                Render: write PGM instead of PPM
+    --fps <N>             Animate: frames per second (default 10), steps are not limited by default,
+                          type `p` to pause or resume, an empty line to step, `q` to quit
+    -h, --help            Print this message";
+
+const README: &str = "README.md";
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static ALLOC: memory::CountingAlloc = memory::CountingAlloc;
+
+const DEFAULT_SEED: u64 = 2024;
+const DEFAULT_SIZE: usize = 100;
+
+enum InputSource {
+    Data,
+    Stdin,
+    File(String),
+}
+
+/// Benchmark history options.
+struct HistoryConfig {
+    label: Option<String>,
+    compare: bool,
+    baseline: Option<String>,
+
+    /// Slowdown flagged, e.g. `0.1` for 10%.
+    threshold: f64,
+}
+
+enum Command {
+    Run {
+        day: u8,
+        parts: Vec<Part>,
+        input: InputSource,
+        format: Format,
+        budget: Option<usize>,
+    },
+    All {
+        days: Vec<u8>,
+        jobs: usize,
+        format: Format,
+        budget: Option<usize>,
+    },
+    Bench {
+        days: Vec<u8>,
+        config: BenchConfig,
+        format: Format,
+
+        /// Seed and size of random inputs.
+        generate: Option<(u64, usize)>,
+        history: HistoryConfig,
+    },
+    Verify {
+        days: Vec<u8>,
+        update_readme: bool,
+    },
+    Accept {
+        day: u8,
+        part: Part,
+        answer: String,
+    },
+    Guess {
+        day: u8,
+        part: Part,
+        answer: String,
+        feedback: Feedback,
+    },
+    Render(RenderConfig),
+    Animate {
+        day: u8,
+        input: InputSource,
+        steps: Range<usize>,
+        fps: u32,
+    },
+    New {
+        day: u8,
+        title: String,
+    },
+    Gen {
+        day: u8,
+        seed: u64,
+        size: usize,
+    },
+    Watch {
+        day: u8,
+        interval: Duration,
+        release: bool,
+    },
+    Help,
+}
+
+struct RenderConfig {
+    day: u8,
+    input: InputSource,
+    steps: Range<usize>,
+    scale: usize,
+    out: Option<PathBuf>,
+    gray: bool,
+}
+
+fn parse_number<T: std::str::FromStr>(name: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{name} requires a value"))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value {value} for {name}"))
+}
+
+fn parse_format(value: Option<&String>) -> Result<Format, String> {
+    value.ok_or("--format requires a value")?.parse()
+}
+
+fn parse_bench_args(args: &[String]) -> Result<Command, String> {
+    let mut days = vec![];
+    let mut config = BenchConfig::default();
+    let mut format = Format::Table;
+    let mut size = None;
+    let mut seed = DEFAULT_SEED;
+    let mut history = HistoryConfig {
+        label: None,
+        compare: false,
+        baseline: None,
+        threshold: 0.1,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--warmup" => config.warmup = parse_number(arg, args.next())?,
+            "--iterations" => config.iterations = parse_number(arg, args.next())?,
+            "--format" => format = parse_format(args.next())?,
+            "--generate" => size = Some(parse_number(arg, args.next())?),
+            "--seed" => seed = parse_number(arg, args.next())?,
+            "--label" => history.label = Some(parse_label(arg, args.next())?),
+            "--compare" => history.compare = true,
+            "--baseline" => history.baseline = Some(parse_label(arg, args.next())?),
+            "--threshold" => {
+                let percent = parse_number::<f64>(arg, args.next())?;
+                if percent.is_nan() || percent < 0.0 {
+                    return Err(String::from("--threshold must not be negative"));
+                }
+                history.threshold = percent / 100.0;
+            }
+            v if v.starts_with('-') => return Err(format!("unknown option {v}")),
+            v => days.push(v.parse::<u8>().map_err(|_| format!("invalid day {v}"))?),
+        }
+    }
+    history.compare |= history.baseline.is_some();
+
+    Ok(Command::Bench {
+        days,
+        config,
+        format,
+        generate: size.map(|size| (seed, size)),
+        history,
+    })
+}
+
+/// Run label, a single word other than `-`.
+fn parse_label(name: &str, value: Option<&String>) -> Result<String, String> {
+    let value = value.ok_or_else(|| format!("{name} requires a value"))?;
+    if value.is_empty() || value == "-" || value.contains(char::is_whitespace) {
+        return Err(format!("invalid value {value:?} for {name}"));
+    }
+    Ok(value.to_owned())
+}
+
+fn parse_verify_args(args: &[String]) -> Result<Command, String> {
+    let mut days = vec![];
+    let mut update_readme = false;
+
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--update-readme" => update_readme = true,
+            v if v.starts_with('-') => return Err(format!("unknown option {v}")),
+            v => days.push(v.parse::<u8>().map_err(|_| format!("invalid day {v}"))?),
+        }
+    }
+
+    if update_readme && !days.is_empty() {
+        // A partial run would mark all other days as not passed.
+        return Err(String::from(
+            "--update-readme verifies all days, do not specify days",
+        ));
+    }
+
+    Ok(Command::Verify {
+        days,
+        update_readme,
+    })
+}
+
+fn parse_accept_args(args: &[String]) -> Result<Command, String> {
+    let [day, part, answer] = args else {
+        return Err(String::from("accept requires day, part and answer"));
+    };
+
+    Ok(Command::Accept {
+        day: day.parse().map_err(|_| format!("invalid day {day}"))?,
+        part: parse_part(part)?,
+        answer: answer.to_owned(),
+    })
+}
+
+fn parse_guess_args(args: &[String]) -> Result<Command, String> {
+    let [day, part, answer, feedback] = args else {
+        return Err(String::from("guess requires day, part, answer and verdict"));
+    };
+
+    Ok(Command::Guess {
+        day: day.parse().map_err(|_| format!("invalid day {day}"))?,
+        part: parse_part(part)?,
+        answer: answer.to_owned(),
+        feedback: feedback.parse()?,
+    })
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    value
+        .parse::<u8>()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| format!("invalid part {value}"))
+}
+
+fn parse_steps(value: Option<&String>) -> Result<Range<usize>, String> {
+    let value = value.ok_or_else(|| String::from("--steps requires a value"))?;
+    let invalid = || format!("invalid steps {value}");
+    match value.split_once("..") {
+        Some((start, end)) => {
+            let start = start.parse::<usize>().map_err(|_| invalid())?;
+            let end = end.parse::<usize>().map_err(|_| invalid())?;
+            if start >= end {
+                return Err(invalid());
+            }
+            Ok(start..end)
+        }
+        None => {
+            let step = value.parse::<usize>().map_err(|_| invalid())?;
+            Ok(step..step + 1)
+        }
+    }
+}
+
+fn parse_render_args(args: &[String]) -> Result<Command, String> {
+    let mut day = None;
+    let mut config = RenderConfig {
+        day: 0,
+        input: InputSource::Data,
+        steps: 0..1,
+        scale: 4,
+        out: None,
+        gray: false,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--steps" => config.steps = parse_steps(args.next())?,
+            "--scale" => config.scale = parse_number(arg, args.next())?,
+            "--out" => match args.next() {
+                Some(path) => config.out = Some(PathBuf::from(path)),
+                None => return Err(String::from("--out requires a path")),
+            },
+            "--gray" => config.gray = true,
+            "--input" => match args.next().map(|x| x.as_str()) {
+                Some("-") => config.input = InputSource::Stdin,
+                Some(path) => config.input = InputSource::File(path.to_owned()),
+                None => return Err(String::from("--input requires a path")),
+            },
+            v if v.starts_with('-') => return Err(format!("unknown option {v}")),
+            v if day.is_none() => {
+                day = Some(v.parse::<u8>().map_err(|_| format!("invalid day {v}"))?)
+            }
+            v => return Err(format!("unexpected argument {v}")),
+        }
+    }
+
+    if config.scale == 0 {
+        return Err(String::from("--scale must be positive"));
+    }
+    config.day = day.ok_or_else(|| String::from("render requires a day"))?;
+    Ok(Command::Render(config))
+}
+
+fn parse_animate_args(args: &[String]) -> Result<Command, String> {
+    let mut day = None;
+    let mut input = InputSource::Data;
+    let mut steps = 0..usize::MAX;
+    let mut fps = 10;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--steps" => steps = parse_steps(args.next())?,
+            "--fps" => fps = parse_number(arg, args.next())?,
+            "--input" => match args.next().map(|x| x.as_str()) {
+                Some("-") => input = InputSource::Stdin,
+                Some(path) => input = InputSource::File(path.to_owned()),
+                None => return Err(String::from("--input requires a path")),
+            },
+            v if v.starts_with('-') => return Err(format!("unknown option {v}")),
+            v if day.is_none() => {
+                day = Some(v.parse::<u8>().map_err(|_| format!("invalid day {v}"))?)
+            }
+            v => return Err(format!("unexpected argument {v}")),
+        }
+    }
+
+    Ok(Command::Animate {
+        day: day.ok_or_else(|| String::from("animate requires a day"))?,
+        input,
+        steps,
+        fps,
+    })
+}
+
+fn parse_new_args(args: &[String]) -> Result<Command, String> {
+    let mut day = None;
+    let mut title = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--title" => match args.next() {
+                Some(v) => title = Some(v.to_owned()),
+                None => return Err(String::from("--title requires a value")),
+            },
+            v if v.starts_with('-') => return Err(format!("unknown option {v}")),
+            v if day.is_none() => {
+                day = Some(v.parse::<u8>().map_err(|_| format!("invalid day {v}"))?)
+            }
+            v => return Err(format!("unexpected argument {v}")),
+        }
+    }
+
+    let day = day.ok_or_else(|| String::from("new requires a day"))?;
+    Ok(Command::New {
+        day,
+        title: title.unwrap_or_else(|| format!("Day {day}")),
+    })
+}
+
+fn parse_gen_args(args: &[String]) -> Result<Command, String> {
+    let mut day = None;
+    let mut seed = DEFAULT_SEED;
+    let mut size = DEFAULT_SIZE;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--seed" => seed = parse_number(arg, args.next())?,
+            "--size" => size = parse_number(arg, args.next())?,
+            v if v.starts_with('-') => return Err(format!("unknown option {v}")),
+            v if day.is_none() => {
+                day = Some(v.parse::<u8>().map_err(|_| format!("invalid day {v}"))?)
+            }
+            v => return Err(format!("unexpected argument {v}")),
+        }
+    }
+
+    Ok(Command::Gen {
+        day: day.ok_or_else(|| String::from("gen requires a day"))?,
+        seed,
+        size,
+    })
+}
+
+fn parse_watch_args(args: &[String]) -> Result<Command, String> {
+    let mut day = None;
+    let mut interval = 500;
+    let mut release = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--interval" => interval = parse_number(arg, args.next())?,
+            "--release" => release = true,
+            v if v.starts_with('-') => return Err(format!("unknown option {v}")),
+            v if day.is_none() => {
+                day = Some(v.parse::<u8>().map_err(|_| format!("invalid day {v}"))?)
+            }
+            v => return Err(format!("unexpected argument {v}")),
+        }
+    }
+
+    if interval == 0 {
+        return Err(String::from("--interval must be positive"));
+    }
+    Ok(Command::Watch {
+        day: day.ok_or_else(|| String::from("watch requires a day"))?,
+        interval: Duration::from_millis(interval),
+        release,
+    })
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(|x| x.as_str()) {
+        Some("bench") => return parse_bench_args(&args[1..]),
+        Some("verify") => return parse_verify_args(&args[1..]),
+        Some("accept") => return parse_accept_args(&args[1..]),
+        Some("guess") => return parse_guess_args(&args[1..]),
+        Some("render") => return parse_render_args(&args[1..]),
+        Some("animate") => return parse_animate_args(&args[1..]),
+        Some("new") => return parse_new_args(&args[1..]),
+        Some("gen") => return parse_gen_args(&args[1..]),
+        Some("watch") => return parse_watch_args(&args[1..]),
+        _ => {}
+    }
+
+    let mut positional = vec![];
+    let mut input = None;
+    let mut jobs = None;
+    let mut format = Format::Table;
+    let mut budget = None;
+    let mut all = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--all" => all = true,
+            "--input" => match args.next().map(|x| x.as_str()) {
+                Some("-") => input = Some(InputSource::Stdin),
+                Some(path) => input = Some(InputSource::File(path.to_owned())),
+                None => return Err(String::from("--input requires a path")),
+            },
+            "--jobs" => jobs = Some(parse_number::<usize>(arg, args.next())?),
+            "--format" => format = parse_format(args.next())?,
+            "--memory-budget" if !cfg!(feature = "count-alloc") => {
+                return Err(String::from(
+                    "--memory-budget needs allocations counted, build with --features count-alloc",
+                ))
+            }
+            "--memory-budget" => match args.next() {
+                Some(v) => budget = Some(memory::parse_size(v)?),
+                None => return Err(String::from("--memory-budget requires a size")),
+            },
+            v if v.starts_with('-') => return Err(format!("unknown option {v}")),
+            v => positional.push(v),
+        }
+    }
+
+    let parse_day = |v: &str| v.parse::<u8>().map_err(|_| format!("invalid day {v}"));
+
+    if all {
+        if input.is_some() {
+            return Err(String::from("--all reads inputs from the data directory"));
+        }
+        let days = positional
+            .into_iter()
+            .map(parse_day)
+            .collect::<Result<_, _>>()?;
+        let jobs = jobs.unwrap_or_else(runner::default_jobs);
+        if jobs == 0 {
+            return Err(String::from("--jobs must be positive"));
+        }
+        return Ok(Command::All {
+            days,
+            jobs,
+            format,
+            budget,
+        });
+    }
+
+    if jobs.is_some() {
+        return Err(String::from("--jobs requires --all"));
+    }
+    let (day, part) = match positional[..] {
+        [] => return Err(String::from("missing day")),
+        [day] => (parse_day(day)?, None),
+        [day, part] => (
+            parse_day(day)?,
+            Some(
+                part.parse::<u8>()
+                    .ok()
+                    .and_then(Part::from_number)
+                    .ok_or_else(|| format!("invalid part {part}"))?,
+            ),
+        ),
+        [_, _, v, ..] => return Err(format!("unexpected argument {v}")),
+    };
+    Ok(Command::Run {
+        day,
+        parts: part.map(|x| vec![x]).unwrap_or(Part::ALL.to_vec()),
+        input: input.unwrap_or(InputSource::Data),
+        format,
+        budget,
+    })
+}
+
+fn read_input(day: u8, source: &InputSource) -> Result<String, String> {
+    match source {
+        InputSource::Data => input::load(day).map_err(|e| e.to_string()),
+        InputSource::Stdin => {
+            let mut buf = String::new();
+            std::io::stdin()
+                .read_to_string(&mut buf)
+                .map_err(|e| format!("failed to read stdin: {e}"))?;
+            Ok(buf.trim_end_matches(['\n', '\r']).to_owned())
+        }
+        InputSource::File(path) => std::fs::read_to_string(path)
+            .map(|x| x.trim_end_matches(['\n', '\r']).to_owned())
+            .map_err(|e| format!("failed to read {path}: {e}")),
+    }
+}
+
+fn find_puzzles(days: &[u8]) -> Result<Vec<&'static dyn Puzzle>, String> {
+    if days.is_empty() {
+        return Ok(DAYS.to_vec());
+    }
+
+    days.iter()
+        .map(|day| days::find(*day).ok_or_else(|| format!("day {day} is not registered")))
+        .collect()
+}
+
+fn run(puzzle: &dyn Puzzle, parts: &[Part], source: &InputSource) -> DayReport {
+    match read_input(puzzle.day(), source) {
+        Ok(input) => runner::run_day(puzzle, &input, parts),
+        Err(msg) => DayReport::no_input(puzzle, parts, msg),
+    }
+}
+
+fn run_bench(
+    puzzles: &[&dyn Puzzle],
+    config: &BenchConfig,
+    format: Format,
+    generate: Option<(u64, usize)>,
+    history_config: &HistoryConfig,
+) -> ExitCode {
+    let path = history::history_path();
+    let history = match History::load(&path) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("error: {}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let run = history.next_run();
+    let revision = history::git_revision(Path::new("."));
+
+    let mut results = vec![];
+    let mut records = vec![];
+    for puzzle in puzzles {
+        let input = match generate {
+            Some((seed, size)) => puzzle
+                .generate(&mut Rng::new(seed), size)
+                .map_err(|e| e.to_string()),
+            None => input::load(puzzle.day()).map_err(|e| e.to_string()),
+        };
+        match input {
+            Ok(input) => {
+                let bench = bench::bench_day(*puzzle, &input, config);
+                records.extend(history::records(
+                    run,
+                    history_config.label.as_deref(),
+                    revision.as_deref(),
+                    &bench,
+                    history::hash_input(&input),
+                ));
+                results.push(bench);
+            }
+            Err(e) => eprintln!("skip day {}: {e}", puzzle.day()),
+        }
+    }
+
+    print!("{}", bench::format_benches(&results, format));
+
+    if let Err(e) = History::append(&path, &records) {
+        eprintln!("error: failed to write {}: {e}", path.display());
+        return ExitCode::FAILURE;
+    }
+
+    let mut regressed = false;
+    if history_config.compare {
+        let comparisons = history::compare(
+            &history,
+            &records,
+            history_config.baseline.as_deref(),
+            history_config.threshold,
+        );
+        regressed = comparisons.iter().any(|x| x.regressed);
+        let table = history::format_comparisons(&comparisons);
+        // Keep structured output parsable.
+        if format == Format::Table {
+            print!("\n{table}");
+        } else {
+            eprint!("{table}");
+        }
+    }
+
+    if !regressed
+        && results
+            .iter()
+            .all(|x| x.stages.iter().all(|stage| stage.result.is_ok()))
+    {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_verify(puzzles: &[&dyn Puzzle], update_readme: bool) -> Result<bool, String> {
+    let path = answers::answers_path();
+    let accepted = Answers::load(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    let mut verdicts = vec![];
+    for puzzle in puzzles {
+        let report = run(*puzzle, &Part::ALL, &InputSource::Data);
+        verdicts.extend(answers::verify(&report, &accepted));
+    }
+
+    let rows = verdicts
+        .iter()
+        .map(|x| {
+            let detail = match &x.verdict {
+                answers::Verdict::Pass => String::new(),
+                answers::Verdict::Fail(v) | answers::Verdict::Missing(v) => v.clone(),
+            };
+            [
+                format!("{:02}", x.day),
+                x.part.number().to_string(),
+                x.verdict.status().to_string(),
+                detail,
+            ]
+        })
+        .collect::<Vec<_>>();
+    print!(
+        "{}",
+        runner::table(&["Day", "Part", "Verdict", "Detail"], &rows)
+    );
+
+    if update_readme {
+        let days = DAYS.iter().map(|x| x.day()).collect::<Vec<_>>();
+        let table = answers::render_status_table(&days, &verdicts);
+        let readme =
+            std::fs::read_to_string(README).map_err(|e| format!("failed to read {README}: {e}"))?;
+        let updated = answers::update_readme(&readme, &table)
+            .ok_or_else(|| format!("status table not found in {README}"))?;
+        std::fs::write(README, updated).map_err(|e| format!("failed to write {README}: {e}"))?;
+        println!("Updated status table in {README}");
+    }
+
+    Ok(verdicts.iter().all(|x| x.verdict.is_pass()))
+}
+
+fn run_accept(day: u8, part: Part, answer: String) -> Result<(), String> {
+    let path = answers::answers_path();
+    let mut accepted = Answers::load(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+    if let Some(prev) = accepted.set(day, part, answer.clone()) {
+        println!("Replaced accepted answer {prev}");
+    }
+    accepted
+        .save(&path)
+        .map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+    println!("Day {day:02} {part}: {answer}");
+    Ok(())
+}
+
+/// A correct guess is also the accepted answer.
+fn run_guess(day: u8, part: Part, answer: String, feedback: Feedback) -> Result<(), String> {
+    let path = journal::journal_path();
+    let mut guesses = Journal::load(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+    guesses.record(day, part, answer.clone(), feedback);
+    guesses
+        .save(&path)
+        .map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+    println!("Day {day:02} {part}: {answer} {feedback}");
+
+    if feedback == Feedback::Correct {
+        run_accept(day, part, answer)?;
+    }
+    Ok(())
+}
+
+/// Warn about answers that earlier guesses rule out, before they are submitted again.
+fn warn_ruled_out(reports: &[DayReport]) {
+    let path = journal::journal_path();
+    let guesses = match Journal::load(&path) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("warning: {}: {e}", path.display());
+            return;
+        }
+    };
+    let (start, end) = if std::io::stderr().is_terminal() {
+        ("\x1b[1;31m", "\x1b[0m")
+    } else {
+        ("", "")
+    };
+    for report in reports {
+        for warning in journal::check(report, &guesses) {
+            eprintln!("{start}WARNING: {warning}{end}");
+        }
+    }
+}
+
+fn run_render(config: RenderConfig) -> Result<(), String> {
+    let puzzle =
+        days::find(config.day).ok_or_else(|| format!("day {} is not registered", config.day))?;
+    let input = read_input(config.day, &config.input)?;
+    let parsed = runner::catch_result(|| puzzle.parse(&input)).map_err(|x| x.to_string())?;
+
+    let dir = config
+        .out
+        .unwrap_or_else(|| Path::new("frames").join(format!("{:02}", config.day)));
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("failed to create {}: {e}", dir.display()))?;
+
+    let palette = Palette::default();
+    let extension = if config.gray { "pgm" } else { "ppm" };
+    let mut written = 0;
+    let mut error = None;
+    let result = puzzle.frames(parsed.as_ref(), config.steps, &mut |step, world| {
+        let path = render::frame_path(&dir, step, extension);
+        let image = Image::from_tiles(world, &palette).scaled(config.scale);
+        match image.save(&path) {
+            Ok(()) => written += 1,
+            Err(e) => error = Some(format!("failed to write {}: {e}", path.display())),
+        }
+        error.is_none()
+    });
+
+    match result {
+        Err(AocError::Unimplemented) => {
+            return Err(format!("day {} has no simulation to render", config.day))
+        }
+        Err(e) => return Err(e.to_string()),
+        Ok(()) => {}
+    }
+    if let Some(msg) = error {
+        return Err(msg);
+    }
+    println!("Wrote {written} frames to {}", dir.display());
+    Ok(())
+}
+
+fn run_animate(day: u8, source: &InputSource, steps: Range<usize>, fps: u32) -> Result<(), String> {
+    let puzzle = days::find(day).ok_or_else(|| format!("day {day} is not registered"))?;
+    let input = read_input(day, source)?;
+    let parsed = runner::catch_result(|| puzzle.parse(&input)).map_err(|x| x.to_string())?;
+
+    let mut animation = Animation::new(fps);
+    // Controls are typed in the terminal, stdin may also be the input.
+    if std::io::stdin().is_terminal() {
+        animation = animation.with_controls();
+    }
+    let mut error = None;
+    let result = puzzle.frames(parsed.as_ref(), steps, &mut |step, world| {
+        if let Err(e) = animation.show(step, world) {
+            error = Some(format!("failed to write to terminal: {e}"));
+        }
+        error.is_none() && !animation.is_quit()
+    });
+    animation
+        .finish()
+        .map_err(|e| format!("failed to write to terminal: {e}"))?;
+
+    match result {
+        Err(AocError::Unimplemented) => {
+            return Err(format!("day {day} has no simulation to animate"))
+        }
+        Err(e) => return Err(e.to_string()),
+        Ok(()) => {}
+    }
+    error.map_or(Ok(()), Err)
+}
+
+/// `cargo <subcommand>` of the toolchain that built this binary.
+fn cargo(subcommand: &str, release: bool) -> process::Command {
+    let program = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = process::Command::new(program);
+    command.args([subcommand, "-q"]);
+    if release {
+        command.arg("--release");
+    }
+    command
+}
+
+fn run_watch(day: u8, interval: Duration, release: bool) -> Result<(), String> {
+    let root = Path::new(".");
+    let source = root.join(format!("src/days/day{day:02}.rs"));
+    if !source.exists() {
+        return Err(format!(
+            "{} not found, watch from the repository root",
+            source.display()
+        ));
+    }
+
+    let paths = || watch::day_paths(root, &input::data_dir(), day);
+    // Taken before each round, so that changes while building are not missed.
+    let mut snapshot = Snapshot::take(&paths());
+    println!(
+        "Watching {} files of day {day:02}, press Ctrl-C to stop",
+        snapshot.len()
+    );
+
+    let mut previous = None;
+    loop {
+        watch_round(day, release, &mut previous);
+
+        let changed = loop {
+            thread::sleep(interval);
+            let next = Snapshot::take(&paths());
+            let changed = snapshot.changed(&next);
+            snapshot = next;
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        println!();
+        for path in changed {
+            println!("Changed {}", path.display());
+        }
+    }
+}
+
+/// Build, then run tests and both parts of `day`, printing answers against `previous`.
+fn watch_round(day: u8, release: bool, previous: &mut Option<BTreeMap<Part, String>>) {
+    // Compiler errors go straight to the terminal.
+    let built = cargo("build", release).args(["--bin", "aoc"]).status();
+    if !built.is_ok_and(|x| x.success()) {
+        println!("Build failed");
+        return;
+    }
+
+    match cargo("test", release)
+        .args(["--lib", &format!("day{day:02}")])
+        .output()
+    {
+        Ok(output) => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            match stdout.lines().find_map(|x| x.strip_prefix("test result: ")) {
+                Some(summary) => println!("Tests: {summary}"),
+                None => print!(
+                    "Tests did not run\n{}",
+                    String::from_utf8_lossy(&output.stderr)
+                ),
+            }
+            // Names of failed tests are listed after the last `failures:` line.
+            if let Some((_, failures)) = stdout.rsplit_once("failures:\n") {
+                failures
+                    .lines()
+                    .map(str::trim)
+                    .filter(|x| !x.is_empty() && !x.starts_with("test result"))
+                    .for_each(|name| println!("  failed {name}"));
+            }
+        }
+        Err(e) => println!("Tests did not run: {e}"),
+    }
+
+    let day_arg = day.to_string();
+    let output = cargo("run", release)
+        .args(["--bin", "aoc", "--", &day_arg, "--format", "csv"])
+        .stderr(Stdio::inherit())
+        .output();
+    let answers = match output {
+        Ok(output) => watch::parse_answers(&String::from_utf8_lossy(&output.stdout)),
+        Err(e) => Err(e.to_string()),
+    };
+    match answers {
+        Ok(answers) => {
+            for line in watch::diff_answers(previous.as_ref(), &answers) {
+                println!("{line}");
+            }
+            *previous = Some(answers);
+        }
+        Err(e) => println!("Run failed: {e}"),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let command = match parse_args(&args) {
+        Ok(v) => v,
+        Err(msg) => {
+            eprintln!("error: {msg}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    runner::install_panic_hook();
+
+    let (reports, format) = match command {
+        Command::Help => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Command::Run {
+            day,
+            parts,
+            input,
+            format,
+            budget,
+        } => {
+            let Some(puzzle) = days::find(day) else {
+                eprintln!("error: day {day} is not registered");
+                return ExitCode::from(2);
+            };
+            memory::set_budget(budget);
+            (vec![run(puzzle, &parts, &input)], format)
+        }
+        Command::Bench {
+            days,
+            config,
+            format,
+            generate,
+            history,
+        } => {
+            let puzzles = match find_puzzles(&days) {
+                Ok(v) => v,
+                Err(msg) => {
+                    eprintln!("error: {msg}");
+                    return ExitCode::from(2);
+                }
+            };
+            return run_bench(&puzzles, &config, format, generate, &history);
+        }
+        Command::Verify {
+            days,
+            update_readme,
+        } => {
+            let result = find_puzzles(&days).and_then(|x| run_verify(&x, update_readme));
+            return match result {
+                Ok(true) => ExitCode::SUCCESS,
+                Ok(false) => ExitCode::FAILURE,
+                Err(msg) => {
+                    eprintln!("error: {msg}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Command::Accept { day, part, answer } => {
+            return match run_accept(day, part, answer) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(msg) => {
+                    eprintln!("error: {msg}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Command::Guess {
+            day,
+            part,
+            answer,
+            feedback,
+        } => {
+            return match run_guess(day, part, answer, feedback) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(msg) => {
+                    eprintln!("error: {msg}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Command::Render(config) => {
+            return match run_render(config) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(msg) => {
+                    eprintln!("error: {msg}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Command::Animate {
+            day,
+            input,
+            steps,
+            fps,
+        } => {
+            return match run_animate(day, &input, steps, fps) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(msg) => {
+                    eprintln!("error: {msg}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Command::New { day, title } => {
+            return match scaffold::create(Path::new("."), day, &title) {
+                Ok(paths) => {
+                    for path in paths {
+                        println!("Wrote {}", path.display());
+                    }
+                    ExitCode::SUCCESS
+                }
+                Err(msg) => {
+                    eprintln!("error: {msg}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Command::Watch {
+            day,
+            interval,
+            release,
+        } => {
+            return match run_watch(day, interval, release) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(msg) => {
+                    eprintln!("error: {msg}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Command::Gen { day, seed, size } => {
+            let Some(puzzle) = days::find(day) else {
+                eprintln!("error: day {day} is not registered");
+                return ExitCode::from(2);
+            };
+            return match puzzle.generate(&mut Rng::new(seed), size) {
+                Ok(input) => {
+                    print!("{input}");
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("error: day {day}: {e}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Command::All {
+            days,
+            jobs,
+            format,
+            budget,
+        } => {
+            let puzzles = match find_puzzles(&days) {
+                Ok(v) => v,
+                Err(msg) => {
+                    eprintln!("error: {msg}");
+                    return ExitCode::from(2);
+                }
+            };
+            memory::set_budget(budget);
+            let summary = runner::run_days(&puzzles, &Part::ALL, jobs, |puzzle, parts| {
+                run(puzzle, parts, &InputSource::Data)
+            });
+            print!("{}", runner::format_reports(&summary.reports, format));
+            warn_ruled_out(&summary.reports);
+            // Keep structured output parsable.
+            if format == Format::Table {
+                println!("{summary}");
+            } else {
+                eprintln!("{summary}");
+            }
+            return exit_code(&summary.reports);
+        }
+    };
+
+    print!("{}", runner::format_reports(&reports, format));
+    warn_ruled_out(&reports);
+    exit_code(&reports)
+}
+
+fn exit_code(reports: &[DayReport]) -> ExitCode {
+    if reports.iter().all(|x| x.all_solved()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,69 @@
+//! Generate a test for every part with an expected answer in every fixture.
+//!
+//! See `src/fixtures.rs` for the fixture format.
+
+use std::fmt::Write;
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/fixture_header.rs");
+    println!("cargo:rerun-if-changed=fixtures");
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut out = String::new();
+
+    let mut day_dirs = std::fs::read_dir(root.join("fixtures"))
+        .map(|x| x.filter_map(|x| x.ok()).map(|x| x.path()).collect())
+        .unwrap_or_else(|_| vec![]);
+    day_dirs.sort();
+
+    for day_dir in day_dirs {
+        let Some(day) = day_dir
+            .file_name()
+            .and_then(|x| x.to_str())
+            .and_then(|x| x.parse::<u8>().ok())
+        else {
+            continue;
+        };
+
+        let mut files = std::fs::read_dir(&day_dir)
+            .unwrap()
+            .filter_map(|x| x.ok())
+            .map(|x| x.path())
+            .filter(|x| x.extension().is_some_and(|ext| ext == "txt"))
+            .collect::<Vec<_>>();
+        files.sort();
+
+        for file in files {
+            let name = file
+                .file_stem()
+                .unwrap()
+                .to_string_lossy()
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect::<String>();
+            let content = std::fs::read_to_string(&file).unwrap();
+            let (header, _) =
+                parse_header(&content).unwrap_or_else(|e| panic!("{}: {e}", file.display()));
+            for part in header.expected.keys() {
+                if let Some(reason) = header.ignored.get(part) {
+                    writeln!(out, "#[ignore = {reason:?}]").unwrap();
+                }
+                writeln!(
+                    out,
+                    "#[test]\nfn day{day:02}_{name}_part{part}() {{\n    \
+                     let path = std::path::Path::new({path:?});\n    \
+                     crate::fixtures::check({day}, crate::Part::from_number({part}).unwrap(), path).unwrap();\n}}\n",
+                    path = file.display().to_string(),
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(Path::new(&out_dir).join("fixture_tests.rs"), out).unwrap();
+}
+
+include!("src/fixture_header.rs");
@@ -0,0 +1,237 @@
+//! Animate grids in the terminal with ANSI escape codes.
+//!
+//! Each frame is redrawn in place, tiles are colored by a [`Palette`]. With controls enabled,
+//! commands are read from stdin, one per line:
+//!
+//! ```text
+//! p    pause or resume
+//!      (empty line) show the next frame when paused
+//! q    quit
+//! ```
+//!
+//! Simulations drive it through [`Solution::frames`](crate::Solution::frames), see `aoc animate`.
+
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::grid::Grid;
+use crate::render::{Palette, Rgb};
+
+const CLEAR_SCREEN: &str = "\x1b[2J";
+const CURSOR_HOME: &str = "\x1b[H";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+const RESET: &str = "\x1b[0m";
+
+/// Command read from stdin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// Pause or resume.
+    Pause,
+
+    /// Show the next frame when paused.
+    Step,
+
+    Quit,
+}
+
+impl Control {
+    pub fn parse(line: &str) -> Option<Self> {
+        match line.trim() {
+            "p" => Some(Control::Pause),
+            "" => Some(Control::Step),
+            "q" => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// `grid` with colored tiles, lines end with `\n`.
+pub fn ansi_frame(grid: &Grid<char>, palette: &Palette) -> String {
+    let mut out = String::new();
+    for row in grid.rows() {
+        let mut last: Option<Rgb> = None;
+        for tile in row {
+            let color = palette.color(*tile);
+            if last != Some(color) {
+                let [r, g, b] = color;
+                out.push_str(&format!("\x1b[38;2;{r};{g};{b}m"));
+                last = Some(color);
+            }
+            out.push(*tile);
+        }
+        out.push_str(RESET);
+        out.push('\n');
+    }
+    out
+}
+
+/// Terminal animation writes to `W`, stdout by default.
+pub struct Animation<W: Write = io::Stdout> {
+    out: W,
+    palette: Palette,
+    frame_time: Duration,
+    controls: Option<Receiver<Control>>,
+    paused: bool,
+    quit: bool,
+    started: bool,
+    last_frame: Option<Instant>,
+}
+
+impl Animation {
+    /// Animation on stdout at `fps` frames per second.
+    pub fn new(fps: u32) -> Self {
+        Self::with_writer(io::stdout(), fps)
+    }
+
+    /// Read pause, step and quit commands from stdin.
+    pub fn with_controls(mut self) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for line in io::stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if let Some(control) = Control::parse(&line) {
+                    if sender.send(control).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        self.controls = Some(receiver);
+        self
+    }
+}
+
+impl<W: Write> Animation<W> {
+    pub fn with_writer(out: W, fps: u32) -> Self {
+        Self {
+            out,
+            palette: Palette::default(),
+            frame_time: Duration::from_secs(1) / fps.max(1),
+            controls: None,
+            paused: false,
+            quit: false,
+            started: false,
+            last_frame: None,
+        }
+    }
+
+    pub fn with_palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Whether quit is requested, later frames are ignored.
+    pub fn is_quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Draw frame `step` over the previous one, waits for the frame rate or controls.
+    pub fn show(&mut self, step: usize, grid: &Grid<char>) -> io::Result<()> {
+        self.handle_controls();
+        if self.quit {
+            return Ok(());
+        }
+
+        if let Some(last) = self.last_frame {
+            if let Some(wait) = self.frame_time.checked_sub(last.elapsed()) {
+                thread::sleep(wait);
+            }
+        }
+
+        if !self.started {
+            write!(self.out, "{CLEAR_SCREEN}{HIDE_CURSOR}")?;
+            self.started = true;
+        }
+        let status = if self.paused { "paused" } else { "" };
+        writeln!(
+            self.out,
+            "{CURSOR_HOME}{}step {step} {status:<6}",
+            ansi_frame(grid, &self.palette)
+        )?;
+        self.out.flush()?;
+        self.last_frame = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Restore the cursor.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if self.started {
+            write!(self.out, "{RESET}{SHOW_CURSOR}")?;
+            self.out.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Apply pending commands, blocks until the next step while paused.
+    fn handle_controls(&mut self) {
+        let Some(controls) = &self.controls else {
+            return;
+        };
+        loop {
+            let control = if self.paused {
+                controls.recv().ok()
+            } else {
+                match controls.try_recv() {
+                    Ok(v) => Some(v),
+                    Err(TryRecvError::Empty) => return,
+                    Err(TryRecvError::Disconnected) => None,
+                }
+            };
+            match control {
+                Some(Control::Pause) => self.paused = !self.paused,
+                Some(Control::Step) if self.paused => return,
+                Some(Control::Step) => {}
+                Some(Control::Quit) => {
+                    self.quit = true;
+                    return;
+                }
+                // Stdin is closed, nobody can resume.
+                None => {
+                    self.controls = None;
+                    self.paused = false;
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ansi_frame() {
+        let grid = Grid::parse("#.\n..").unwrap();
+        let palette = Palette::empty().with('#', [1, 2, 3]).with('.', [4, 5, 6]);
+        assert_eq!(
+            ansi_frame(&grid, &palette),
+            "\x1b[38;2;1;2;3m#\x1b[38;2;4;5;6m.\x1b[0m\n\x1b[38;2;4;5;6m..\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_animation() {
+        let grid = Grid::parse("@.").unwrap();
+        let mut animation = Animation::with_writer(vec![], 1000);
+        animation.show(0, &grid).unwrap();
+        animation.show(1, &grid).unwrap();
+        animation.finish().unwrap();
+        let out = String::from_utf8(animation.out).unwrap();
+        assert_eq!(out.matches(CLEAR_SCREEN).count(), 1);
+        assert_eq!(out.matches(CURSOR_HOME).count(), 2);
+        assert!(out.contains("step 1"));
+        assert!(out.ends_with(SHOW_CURSOR));
+    }
+
+    #[test]
+    fn test_control() {
+        assert_eq!(Control::parse("p\n"), Some(Control::Pause));
+        assert_eq!(Control::parse(""), Some(Control::Step));
+        assert_eq!(Control::parse(" q "), Some(Control::Quit));
+        assert_eq!(Control::parse("x"), None);
+    }
+}
@@ -0,0 +1,269 @@
+//! Accepted answers and verification against them.
+//!
+//! Answers are personal, they are saved in `answers.txt` in the data directory, one part per line:
+//!
+//! ```text
+//! # day part answer
+//! 01 1 1530215
+//! 01 2 26800609
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::line_file;
+use crate::runner::{DayReport, Outcome};
+use crate::Part;
+
+/// Name of the answers file in the data directory.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Path of the answers file in use.
+pub fn answers_path() -> PathBuf {
+    crate::input::data_dir().join(ANSWERS_FILE)
+}
+
+/// Accepted answers of all days.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl Answers {
+    /// Parse answers file content, a [`line_file`].
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+        for (number, line) in line_file::entries(content) {
+            let invalid = || format!("invalid answer at line {number}: {line:?}");
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let day = fields
+                .next()
+                .and_then(|x| x.parse::<u8>().ok())
+                .ok_or_else(invalid)?;
+            let part = fields
+                .next()
+                .and_then(|x| x.parse::<u8>().ok())
+                .and_then(Part::from_number)
+                .ok_or_else(invalid)?;
+            let answer = fields
+                .next()
+                .map(|x| x.trim())
+                .filter(|x| !x.is_empty())
+                .ok_or_else(invalid)?;
+            answers.insert((day, part), answer.to_owned());
+        }
+
+        Ok(Self { answers })
+    }
+
+    /// Load answers from `path`, a missing file has no answer.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        line_file::load(path, Self::parse)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|x| x.as_str())
+    }
+
+    /// Record the accepted answer, return the replaced one.
+    pub fn set(&mut self, day: u8, part: Part, answer: String) -> Option<String> {
+        self.answers.insert((day, part), answer)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part answer")?;
+        for ((day, part), answer) in self.answers.iter() {
+            writeln!(f, "{day:02} {} {answer}", part.number())?;
+        }
+        Ok(())
+    }
+}
+
+/// Result of checking a part against its accepted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+
+    /// Wrong answer, or the part failed to produce one.
+    Fail(String),
+
+    /// No accepted answer recorded, or no input to run with.
+    Missing(String),
+}
+
+impl Verdict {
+    pub fn is_pass(&self) -> bool {
+        matches!(self, Verdict::Pass)
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail(_) => "fail",
+            Verdict::Missing(_) => "missing",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartVerdict {
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+}
+
+/// Check all parts in `report` against `answers`.
+pub fn verify(report: &DayReport, answers: &Answers) -> Vec<PartVerdict> {
+    report
+        .parts
+        .iter()
+        .map(|part| {
+            let expected = answers.get(report.day, part.part);
+            let verdict = match (&part.outcome, expected) {
+                (Outcome::NoInput(msg), _) => Verdict::Missing(msg.clone()),
+                (Outcome::Solved(answer), Some(expected)) => {
+                    let answer = answer.to_string();
+                    if answer == expected {
+                        Verdict::Pass
+                    } else {
+                        Verdict::Fail(format!("expected {expected}, got {answer}"))
+                    }
+                }
+                (Outcome::Solved(answer), None) => {
+                    Verdict::Missing(format!("no accepted answer, got {answer}"))
+                }
+                (outcome, _) => Verdict::Fail(format!("{}: {outcome}", outcome.status())),
+            };
+            PartVerdict {
+                day: report.day,
+                part: part.part,
+                verdict,
+            }
+        })
+        .collect()
+}
+
+/// Render the status table in README.
+///
+/// `days` are all registered days, parts without verdict are not passed.
+pub fn render_status_table(days: &[u8], verdicts: &[PartVerdict]) -> String {
+    let mut out = String::from("| Date   | Status    |\n|--------|-----------|\n");
+    for day in days {
+        let status = Part::ALL
+            .iter()
+            .map(|part| {
+                let passed = verdicts
+                    .iter()
+                    .any(|x| x.day == *day && x.part == *part && x.verdict.is_pass());
+                format!("P{} {}", part.number(), if passed { '√' } else { '×' })
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        out.push_str(&format!("| Day {day:02} | {status} |\n"));
+    }
+    out
+}
+
+/// Replace the status table in `readme` with `table`.
+///
+/// The status table is the first table after the `## Status` heading. Return `None` if not found.
+pub fn update_readme(readme: &str, table: &str) -> Option<String> {
+    let section = readme.find("## Status")?;
+    let lines = readme[section..].split_inclusive('\n').collect::<Vec<_>>();
+
+    let first = lines.iter().position(|x| x.starts_with('|'))?;
+    let count = lines[first..]
+        .iter()
+        .take_while(|x| x.starts_with('|'))
+        .count();
+
+    let start = section + lines[..first].iter().map(|x| x.len()).sum::<usize>();
+    let end = start
+        + lines[first..first + count]
+            .iter()
+            .map(|x| x.len())
+            .sum::<usize>();
+
+    let mut table = table.to_owned();
+    if end == readme.len() && !readme.ends_with('\n') {
+        table.pop();
+    }
+
+    Some(format!("{}{table}{}", &readme[..start], &readme[end..]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runner::PartReport;
+    use crate::Answer;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("# comment\n01 1 42\n\n1 2 hello world\n").unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("42"));
+        assert_eq!(answers.get(1, Part::Two), Some("hello world"));
+        assert_eq!(answers.get(2, Part::One), None);
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+
+        assert!(Answers::parse("01 3 42").is_err());
+        assert!(Answers::parse("01 1").is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut answers = Answers::default();
+        answers.set(3, Part::One, String::from("161"));
+        answers.set(3, Part::Two, String::from("48"));
+        let part = |part, outcome| PartReport {
+            part,
+            outcome,
+            elapsed: Duration::ZERO,
+            memory: None,
+        };
+        let report = DayReport {
+            day: 3,
+            title: "",
+            parse_elapsed: Duration::ZERO,
+            parse_memory: None,
+            parts: vec![
+                part(Part::One, Outcome::Solved(Answer::UInt(161))),
+                part(Part::Two, Outcome::Solved(Answer::UInt(49))),
+            ],
+        };
+        let verdicts = verify(&report, &answers)
+            .into_iter()
+            .map(|x| x.verdict.status())
+            .collect::<Vec<_>>();
+        assert_eq!(verdicts, ["pass", "fail"]);
+
+        let verdicts = verify(&report, &Answers::default());
+        assert!(matches!(verdicts[0].verdict, Verdict::Missing(_)));
+    }
+
+    #[test]
+    fn test_update_readme() {
+        let readme = "# Title\n\n## Status\n\n* √ Passed.\n\n| Date   | Status    |\n|--------|-----------|\n| Day 01 | P1 √ P2 × |\n\nfooter\n";
+        let verdicts = [PartVerdict {
+            day: 1,
+            part: Part::Two,
+            verdict: Verdict::Pass,
+        }];
+        let table = render_status_table(&[1, 2], &verdicts);
+        let updated = update_readme(readme, &table).unwrap();
+        assert_eq!(
+            updated,
+            "# Title\n\n## Status\n\n* √ Passed.\n\n| Date   | Status    |\n|--------|-----------|\n| Day 01 | P1 × P2 √ |\n| Day 02 | P1 × P2 × |\n\nfooter\n"
+        );
+        assert!(update_readme("no status", &table).is_none());
+    }
+}
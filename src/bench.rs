@@ -0,0 +1,289 @@
+//! Benchmark parsing and solving of puzzles.
+//!
+//! Parsing is timed separately from each part: the input is parsed once before timing the parts,
+//! so part timings do not include parsing.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::AocResult;
+use crate::json::Json;
+use crate::runner::{self, Format, Outcome};
+use crate::{Part, Puzzle};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Runs before measuring, results are dropped.
+    pub warmup: usize,
+
+    /// Measured runs.
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+/// Statistics of measured runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub iterations: usize,
+}
+
+impl Stats {
+    /// Calculate statistics of `samples`, `None` if there is no sample.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let len = sorted.len();
+
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+
+        // Nearest-rank percentile.
+        let p95_rank = (len * 95).div_ceil(100);
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            p95: sorted[p95_rank.max(1) - 1],
+            iterations: len,
+        })
+    }
+}
+
+/// What a row of benchmark result measures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    /// Short name, `parse`, `part1` or `part2`.
+    pub fn name(&self) -> String {
+        match self {
+            Stage::Parse => String::from("parse"),
+            Stage::Solve(part) => format!("part{}", part.number()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StageBench {
+    pub stage: Stage,
+
+    /// Statistics, or the reason why the stage can not be measured.
+    pub result: Result<Stats, Outcome>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u8,
+    pub title: &'static str,
+    pub stages: Vec<StageBench>,
+}
+
+/// Run `f` `config.warmup + config.iterations` times and collect timings of measured runs.
+///
+/// Stop at the first panic.
+fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> AocResult<T>) -> Result<Stats, Outcome> {
+    for _ in 0..config.warmup {
+        runner::catch_result(|| black_box(f()))?;
+    }
+
+    let mut samples = Vec::with_capacity(config.iterations);
+    for _ in 0..config.iterations.max(1) {
+        let start = Instant::now();
+        runner::catch_result(|| black_box(f()))?;
+        samples.push(start.elapsed());
+    }
+
+    Ok(Stats::from_samples(&samples).unwrap())
+}
+
+/// Benchmark parsing `input` and solving both parts of `puzzle`.
+pub fn bench_day(puzzle: &dyn Puzzle, input: &str, config: &BenchConfig) -> DayBench {
+    let mut stages = vec![StageBench {
+        stage: Stage::Parse,
+        result: measure(config, || puzzle.parse(input)),
+    }];
+
+    let parsed = runner::catch_result(|| puzzle.parse(input));
+    for part in Part::ALL {
+        let result = match &parsed {
+            Ok(parsed) => measure(config, || puzzle.solve(part, parsed.as_ref())),
+            Err(outcome) => Err(outcome.clone()),
+        };
+        stages.push(StageBench {
+            stage: Stage::Solve(part),
+            result,
+        });
+    }
+
+    DayBench {
+        day: puzzle.day(),
+        title: puzzle.title(),
+        stages,
+    }
+}
+
+/// Format benchmark results in `format`, one row per stage.
+pub fn format_benches(benches: &[DayBench], format: Format) -> String {
+    if format == Format::Table {
+        return format_table(benches);
+    }
+
+    let header = [
+        "day",
+        "title",
+        "stage",
+        "status",
+        "min_ns",
+        "median_ns",
+        "p95_ns",
+        "iterations",
+    ];
+    let ns = |x: Duration| Json::from(x.as_nanos() as u64);
+    let mut rows = vec![];
+    for bench in benches {
+        for stage in bench.stages.iter() {
+            let [status, min, median, p95, iterations] = match &stage.result {
+                Ok(stats) => [
+                    Json::from("ok"),
+                    ns(stats.min),
+                    ns(stats.median),
+                    ns(stats.p95),
+                    Json::from(stats.iterations),
+                ],
+                Err(outcome) => [
+                    Json::from(outcome.status()),
+                    Json::Null,
+                    Json::Null,
+                    Json::Null,
+                    Json::Null,
+                ],
+            };
+            rows.push([
+                Json::from(bench.day),
+                Json::from(bench.title),
+                Json::from(stage.stage.name()),
+                status,
+                min,
+                median,
+                p95,
+                iterations,
+            ]);
+        }
+    }
+
+    runner::format_rows(&header, rows, format)
+}
+
+/// Format benchmark results of all days as a comparison table.
+pub fn format_table(benches: &[DayBench]) -> String {
+    let header = ["Day", "Title", "Stage", "Min", "Median", "P95", "Runs"];
+    let mut rows = vec![];
+    for bench in benches {
+        for stage in bench.stages.iter() {
+            let stage_name = match stage.stage {
+                Stage::Parse => String::from("parse"),
+                Stage::Solve(part) => format!("part {}", part.number()),
+            };
+            let cells = match &stage.result {
+                Ok(stats) => [
+                    format!("{:.2?}", stats.min),
+                    format!("{:.2?}", stats.median),
+                    format!("{:.2?}", stats.p95),
+                    stats.iterations.to_string(),
+                ],
+                Err(outcome) => [
+                    outcome.status().to_string(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                ],
+            };
+            let [min, median, p95, runs] = cells;
+            rows.push([
+                format!("{:02}", bench.day),
+                bench.title.to_string(),
+                stage_name,
+                min,
+                median,
+                p95,
+                runs,
+            ]);
+        }
+    }
+
+    runner::table(&header, &rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ms(v: u64) -> Duration {
+        Duration::from_millis(v)
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=20).rev().map(ms).collect::<Vec<_>>();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, Duration::from_micros(10500));
+        assert_eq!(stats.p95, ms(19));
+        assert_eq!(stats.iterations, 20);
+    }
+
+    #[test]
+    fn test_stats_single() {
+        let stats = Stats::from_samples(&[ms(3)]).unwrap();
+        assert_eq!((stats.min, stats.median, stats.p95), (ms(3), ms(3), ms(3)));
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_format_benches() {
+        let bench = DayBench {
+            day: 3,
+            title: "Mull It Over",
+            stages: vec![
+                StageBench {
+                    stage: Stage::Parse,
+                    result: Stats::from_samples(&[ms(3)]).ok_or(Outcome::Unimplemented),
+                },
+                StageBench {
+                    stage: Stage::Solve(Part::Two),
+                    result: Err(Outcome::Unimplemented),
+                },
+            ],
+        };
+        assert_eq!(
+            format_benches(std::slice::from_ref(&bench), Format::Csv),
+            "day,title,stage,status,min_ns,median_ns,p95_ns,iterations\n\
+             3,Mull It Over,parse,ok,3000000,3000000,3000000,1\n\
+             3,Mull It Over,part2,todo,,,,\n"
+        );
+        assert!(format_benches(&[bench], Format::Json).contains(
+            r#"{"day":3,"title":"Mull It Over","stage":"part2","status":"todo","min_ns":null,"#
+        ));
+    }
+}
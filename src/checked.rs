@@ -0,0 +1,144 @@
+//! Arithmetic of solvers that can report overflow.
+//!
+//! With the `checked-math` feature every operation is checked and an overflow is an
+//! [`AocError::Overflow`] instead of a wrong answer. Without it they are the plain operators, which
+//! panic on overflow in debug builds and wrap in release builds.
+//!
+//! ```text
+//! let total = checked::add(total, checked::mul(price, count)?)?;
+//! ```
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::error::{AocError, AocResult};
+
+/// Whether operations are checked.
+pub const ENABLED: bool = cfg!(feature = "checked-math");
+
+/// Primitive integers.
+pub trait Int:
+    Copy
+    + PartialEq
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    const NAME: &'static str;
+    const ZERO: Self;
+    const ONE: Self;
+    const TEN: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),+) => {
+        $(
+            impl Int for $t {
+                const NAME: &'static str = stringify!($t);
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const TEN: Self = 10;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+            }
+        )+
+    };
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+fn overflow<T: Int>(lhs: T, op: &str, rhs: T) -> AocError {
+    AocError::Overflow(format!("{lhs} {op} {rhs} in {}", T::NAME))
+}
+
+pub fn add<T: Int>(lhs: T, rhs: T) -> AocResult<T> {
+    if ENABLED {
+        lhs.checked_add(rhs).ok_or_else(|| overflow(lhs, "+", rhs))
+    } else {
+        Ok(lhs + rhs)
+    }
+}
+
+pub fn sub<T: Int>(lhs: T, rhs: T) -> AocResult<T> {
+    if ENABLED {
+        lhs.checked_sub(rhs).ok_or_else(|| overflow(lhs, "-", rhs))
+    } else {
+        Ok(lhs - rhs)
+    }
+}
+
+pub fn mul<T: Int>(lhs: T, rhs: T) -> AocResult<T> {
+    if ENABLED {
+        lhs.checked_mul(rhs).ok_or_else(|| overflow(lhs, "*", rhs))
+    } else {
+        Ok(lhs * rhs)
+    }
+}
+
+/// `lhs` followed by the digits of `rhs`, e.g. `concat(12, 345)` is `12345`.
+///
+/// `rhs` must not be negative.
+pub fn concat<T: Int>(lhs: T, rhs: T) -> AocResult<T> {
+    let mut shifted = lhs;
+    let mut rest = rhs;
+    loop {
+        shifted = mul(shifted, T::TEN)?;
+        rest = rest / T::TEN;
+        if rest == T::ZERO {
+            break;
+        }
+    }
+    add(shifted, rhs)
+}
+
+pub fn sum<T: Int>(values: impl IntoIterator<Item = T>) -> AocResult<T> {
+    values.into_iter().try_fold(T::ZERO, add)
+}
+
+pub fn product<T: Int>(values: impl IntoIterator<Item = T>) -> AocResult<T> {
+    values.into_iter().try_fold(T::ONE, mul)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(add(2u8, 3).unwrap(), 5);
+        assert_eq!(sub(2i32, 3).unwrap(), -1);
+        assert_eq!(concat(12u64, 345).unwrap(), 12345);
+        assert_eq!(concat(7u64, 0).unwrap(), 70);
+        assert_eq!(sum([1u32, 2, 3]).unwrap(), 6);
+        assert_eq!(product([2i64, 3, 4]).unwrap(), 24);
+        assert_eq!(product(Vec::<u8>::new()).unwrap(), 1);
+    }
+
+    #[cfg(feature = "checked-math")]
+    #[test]
+    fn test_overflow() {
+        assert_eq!(
+            mul(16u8, 16).unwrap_err().to_string(),
+            "overflow: 16 * 16 in u8"
+        );
+        assert!(sub(0usize, 1).is_err());
+        assert!(concat(u64::MAX / 10, 9).is_err());
+        assert!(sum([i32::MAX, 1]).is_err());
+    }
+}
@@ -0,0 +1,224 @@
+//! Connected regions of a [`Grid`].
+//!
+//! Two orthogonally adjacent cells are connected if the predicate holds for their values in
+//! either order, e.g. `==` for regions of the same plant, or `a + 1 == b` for steps of one up or
+//! down. Both [`flood_fill`] and [`union_find`] take linear time and give the same labels,
+//! numbered in row-major order of the first cell of each region.
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+/// Labeled regions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    labels: Grid<usize>,
+    cells: Vec<Vec<Point>>,
+}
+
+impl Components {
+    /// Label of the region contains `p`.
+    pub fn label(&self, p: Point) -> usize {
+        self.labels[p]
+    }
+
+    /// Label of every cell.
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    /// Cells of region `label`, in row-major order.
+    pub fn cells(&self, label: usize) -> &[Point] {
+        &self.cells[label]
+    }
+
+    /// Number of regions.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Cells of all regions, ordered by label.
+    pub fn iter(&self) -> impl Iterator<Item = &[Point]> {
+        self.cells.iter().map(|x| x.as_slice())
+    }
+
+    fn from_labels(labels: Grid<usize>, count: usize) -> Self {
+        let mut cells = vec![vec![]; count];
+        for (p, label) in labels.iter() {
+            cells[*label].push(p);
+        }
+        Self { labels, cells }
+    }
+}
+
+/// Label regions of equal cells.
+pub fn label_eq<T: PartialEq>(grid: &Grid<T>) -> Components {
+    flood_fill(grid, |a, b| a == b)
+}
+
+/// Label regions by filling from every unlabeled cell.
+pub fn flood_fill<T>(grid: &Grid<T>, mut connected: impl FnMut(&T, &T) -> bool) -> Components {
+    let mut labels = Grid::new(grid.width(), grid.height(), usize::MAX);
+    let mut count = 0;
+    let mut stack = vec![];
+
+    for start in grid.points() {
+        if labels[start] != usize::MAX {
+            continue;
+        }
+        labels[start] = count;
+        stack.push(start);
+        while let Some(p) = stack.pop() {
+            for next in grid.neighbours4(p) {
+                if labels[next] == usize::MAX
+                    && (connected(&grid[p], &grid[next]) || connected(&grid[next], &grid[p]))
+                {
+                    labels[next] = count;
+                    stack.push(next);
+                }
+            }
+        }
+        count += 1;
+    }
+
+    Components::from_labels(labels, count)
+}
+
+/// Label regions by joining every cell with its right and bottom neighbours.
+///
+/// Each pair is tested both ways, as the left and top neighbours are not visited.
+pub fn union_find<T>(grid: &Grid<T>, mut connected: impl FnMut(&T, &T) -> bool) -> Components {
+    let index = |p: Point| p.y * grid.width() + p.x;
+    let mut set = DisjointSet::new(grid.width() * grid.height());
+    for p in grid.points() {
+        for next in [Point::new(p.x + 1, p.y), Point::new(p.x, p.y + 1)] {
+            if grid.contains(next)
+                && (connected(&grid[p], &grid[next]) || connected(&grid[next], &grid[p]))
+            {
+                set.union(index(p), index(next));
+            }
+        }
+    }
+
+    // Number roots in the order they are first seen.
+    let mut root_labels = vec![usize::MAX; grid.width() * grid.height()];
+    let mut count = 0;
+    let mut labels = Grid::new(grid.width(), grid.height(), 0);
+    for p in grid.points() {
+        let root = set.find(index(p));
+        if root_labels[root] == usize::MAX {
+            root_labels[root] = count;
+            count += 1;
+        }
+        labels[p] = root_labels[root];
+    }
+
+    Components::from_labels(labels, count)
+}
+
+/// Disjoint-set forest of elements `0..n`, with union by size and path compression.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl DisjointSet {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    /// Representative of the set contains `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut x = x;
+        while self.parent[x] != root {
+            x = std::mem::replace(&mut self.parent[x], root);
+        }
+        root
+    }
+
+    /// Join sets of `a` and `b`, returns `false` if they are already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Size of the set contains `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_components() {
+        let grid = Grid::parse("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+        let components = label_eq(&grid);
+        assert_eq!(components.len(), 5);
+        assert_eq!(components.cells(0).len(), 4);
+        assert_eq!(
+            components.label(Point::new(3, 3)),
+            components.label(Point::new(2, 1))
+        );
+        assert_eq!(components.cells(2).len(), 4);
+        assert_eq!(union_find(&grid, |a, b| a == b), components);
+
+        // Same plants apart are different regions.
+        let grid = Grid::parse("OXO\nXXX\nOXO").unwrap();
+        assert_eq!(label_eq(&grid).len(), 5);
+        assert_eq!(flood_fill(&grid, |_, _| true).len(), 1);
+    }
+
+    #[test]
+    fn test_asymmetric_predicate() {
+        // 4 to 3 is only a step up from 3, on the right of 4 and under 2.
+        let grid = Grid::parse("12\n43").unwrap();
+        let step_up = |a: &char, b: &char| *a as u8 + 1 == *b as u8;
+        let components = flood_fill(&grid, step_up);
+        assert_eq!(components.len(), 1);
+        assert_eq!(union_find(&grid, step_up), components);
+
+        let grid = Grid::parse("1357\n2468\n3579").unwrap();
+        assert_eq!(union_find(&grid, step_up), flood_fill(&grid, step_up));
+        assert_eq!(flood_fill(&grid, step_up).len(), 4);
+    }
+
+    #[test]
+    fn test_disjoint_set() {
+        let mut set = DisjointSet::new(5);
+        assert!(set.union(0, 1));
+        assert!(set.union(3, 4));
+        assert!(!set.union(1, 0));
+        assert!(set.same(0, 1));
+        assert!(!set.same(1, 3));
+        assert!(set.union(1, 4));
+        assert_eq!(set.size_of(3), 4);
+        assert_eq!(set.size_of(2), 1);
+    }
+}
@@ -0,0 +1,87 @@
+//! CPU time of the current thread, which std does not provide.
+//!
+//! Read with `clock_gettime(CLOCK_THREAD_CPUTIME_ID)` on Linux and macOS, other platforms have no
+//! CPU time:
+//!
+//! ```text
+//! let start = cpu::thread_time();
+//! work();
+//! let used = cpu::elapsed(start);
+//! ```
+
+use std::time::Duration;
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+mod sys {
+    use std::os::raw::{c_int, c_long};
+    use std::time::Duration;
+
+    #[cfg(target_os = "linux")]
+    const CLOCK_THREAD_CPUTIME_ID: c_int = 3;
+    #[cfg(target_os = "macos")]
+    const CLOCK_THREAD_CPUTIME_ID: c_int = 16;
+
+    #[repr(C)]
+    struct Timespec {
+        tv_sec: c_long,
+        tv_nsec: c_long,
+    }
+
+    extern "C" {
+        fn clock_gettime(clock: c_int, tp: *mut Timespec) -> c_int;
+    }
+
+    pub fn thread_time() -> Option<Duration> {
+        let mut ts = Timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: `ts` is a valid `timespec` to write to.
+        if unsafe { clock_gettime(CLOCK_THREAD_CPUTIME_ID, &mut ts) } != 0 {
+            return None;
+        }
+        Some(Duration::new(
+            u64::try_from(ts.tv_sec).ok()?,
+            u32::try_from(ts.tv_nsec).ok()?,
+        ))
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+mod sys {
+    use std::time::Duration;
+
+    pub fn thread_time() -> Option<Duration> {
+        None
+    }
+}
+
+/// CPU time used by the current thread so far, `None` if not available.
+pub fn thread_time() -> Option<Duration> {
+    sys::thread_time()
+}
+
+/// CPU time used by the current thread since `start` of [`thread_time`].
+pub fn elapsed(start: Option<Duration>) -> Option<Duration> {
+    Some(thread_time()?.saturating_sub(start?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[test]
+    fn test_thread_time() {
+        let start = thread_time();
+        let mut x = 0u64;
+        for i in 0..2_000_000u64 {
+            x = std::hint::black_box(x.wrapping_add(i * i));
+        }
+        std::thread::sleep(Duration::from_millis(50));
+        let used = elapsed(start).unwrap();
+        assert!(used > Duration::ZERO);
+        // Sleeping does not count.
+        assert!(used < Duration::from_millis(50));
+    }
+}
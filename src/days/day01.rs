@@ -0,0 +1,78 @@
+use crate::checked;
+use crate::error::{AocError, AocResult};
+use crate::parse::{int, lines, parse_all, separated_pair, spaces, ParseError};
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day01;
+
+type Input = (Vec<i32>, Vec<i32>);
+
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    // Parse into two groups of number.
+    // 55820   53096
+    let pairs: Vec<(i32, i32)> = parse_all(lines(separated_pair(int(), spaces(), int())), input)?;
+    Ok(pairs.into_iter().unzip())
+}
+
+fn solve_part1(input: &Input) -> AocResult<i32> {
+    let (mut first_group, mut second_group) = input.clone();
+    if first_group.is_empty() {
+        return Err(AocError::invalid("no location IDs"));
+    }
+    first_group.sort();
+    second_group.sort();
+    first_group
+        .into_iter()
+        .zip(second_group)
+        .try_fold(0, |acc, (x, y)| {
+            checked::add(acc, checked::sub(x.max(y), x.min(y))?)
+        })
+}
+
+fn solve_part2(input: &Input) -> AocResult<i32> {
+    let (first_group, second_group) = input;
+    if first_group.is_empty() {
+        return Err(AocError::invalid("no location IDs"));
+    }
+    first_group.iter().try_fold(0, |acc, x| {
+        let count = second_group.iter().filter(|y| y == &x).count() as i32;
+        checked::add(acc, checked::mul(*x, count)?)
+    })
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = Input;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
+
+    /// `size` lines, some IDs on the right repeat IDs on the left.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let left = (0..size)
+            .map(|_| rng.range(10000..100000))
+            .collect::<Vec<_>>();
+        let mut out = String::new();
+        for id in left.iter() {
+            let right = if rng.chance(0.3) {
+                *rng.choose(&left)
+            } else {
+                rng.range(10000..100000)
+            };
+            out.push_str(&format!("{id}   {right}\n"));
+        }
+        Ok(out)
+    }
+}
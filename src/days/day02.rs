@@ -0,0 +1,240 @@
+use crate::error::{AocError, AocResult};
+use crate::parse::{int, lines, parse_all, separated, spaces, ParseError};
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day02;
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Order {
+    Increase,
+    Decrease,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse_all(lines(separated(int(), spaces())), input)
+}
+
+fn solve_part1(input: &[Vec<i32>]) -> i32 {
+    fn check_report(xs: Vec<i32>) -> i32 {
+        if xs.len() < 2 {
+            return 1;
+        }
+
+        let order = if xs[0] < xs[1] {
+            Order::Increase
+        } else {
+            Order::Decrease
+        };
+
+        let mut prev: Option<i32> = None;
+
+        for x in xs.into_iter() {
+            match prev {
+                Some(v) => {
+                    let distance = (v - x).abs();
+                    if distance > 3 || distance <= 0 {
+                        return 0;
+                    }
+
+                    match order {
+                        Order::Increase if v > x => return 0,
+                        Order::Decrease if v < x => return 0,
+                        _ => prev = Some(x),
+                    }
+                }
+                None => prev = Some(x),
+            }
+        }
+
+        1
+    }
+
+    input.iter().fold(0, |acc, x| acc + check_report(x.clone()))
+}
+
+fn check_report2(xs: Vec<i32>) -> AocResult<i32> {
+    if xs.len() < 2 {
+        return Ok(1);
+    }
+    if xs.len() < 5 {
+        return Err(AocError::invalid(format!(
+            "report {xs:?} has less than 5 levels"
+        )));
+    }
+
+    let mut already_toleranting = false;
+
+    let mut backup: Option<(&i32, &i32)> = None;
+    let mut prev: Option<&i32> = None;
+
+    fn sample_order(x: &i32, y: &i32) -> Option<Order> {
+        if x < y {
+            Some(Order::Increase)
+        } else if x > y {
+            Some(Order::Decrease)
+        } else {
+            None
+        }
+    }
+
+    fn work_with(x: &i32, y: &i32, order: &Order) -> bool {
+        let distance = (x - y).abs();
+        if distance > 3 || distance <= 0 {
+            return false;
+        }
+
+        match order {
+            Order::Increase if x > y => false,
+            Order::Decrease if x < y => false,
+            _ => true,
+        }
+    }
+
+    // FIXME: Equality not works here.
+    // 0 1 4 4 5
+    //  i i e i   -> remove '4'
+    //
+    // 0 1 4 4 4
+    //  i i e e   -> NOT PASSED
+    //
+    // 1 2 1 2 1
+    //  i d i d   -> NOT PASSED
+    let orders = vec![
+        sample_order(&xs[0], &xs[1]),
+        sample_order(&xs[1], &xs[2]),
+        sample_order(&xs[2], &xs[3]),
+        sample_order(&xs[3], &xs[4]),
+    ];
+
+    if orders.iter().filter(|x| x.is_none()).count() >= 2 {
+        // To many equals.
+        return Ok(0);
+    }
+
+    let inc_count = orders
+        .into_iter()
+        .flatten()
+        .filter(|x| x == &Order::Increase)
+        .count();
+
+    let order = if inc_count > 2 {
+        Order::Increase
+    } else if inc_count < 2 {
+        Order::Decrease
+    } else {
+        // i i d d
+        return Ok(0);
+    };
+
+    let xs = if sample_order(&xs[0], &xs[1]) != Some(order.clone()) {
+        already_toleranting = true;
+        backup = Some((&xs[0], &xs[1]));
+        prev = Some(&xs[0]);
+        &xs[1..]
+    } else {
+        &xs[0..]
+    };
+
+    for x in xs.iter() {
+        if let Some((p2, p)) = &backup {
+            // Already tolerating.
+            if work_with(p, x, &order) || work_with(p2, x, &order) {
+                backup = None;
+                prev = Some(x);
+                continue;
+            }
+            return Ok(0);
+        }
+
+        match prev {
+            Some(p) => {
+                if work_with(p, x, &order) {
+                    prev = Some(x);
+                } else {
+                    if already_toleranting {
+                        return Ok(0);
+                    } else {
+                        already_toleranting = true;
+                        backup = Some((p, x));
+                        prev = Some(x);
+                        continue;
+                    }
+                }
+            }
+            None => prev = Some(x),
+        }
+    }
+
+    Ok(1)
+}
+
+fn solve_part2(input: &[Vec<i32>]) -> AocResult<i32> {
+    input.iter().try_fold(0, |acc, x| {
+        let ret = check_report2(x.clone())?;
+        if ret == 0 {
+            crate::debug!("unsafe report {x:?}");
+        }
+
+        Ok(acc + ret)
+    })
+}
+
+// Not works for:
+//
+// [18, 22, 23, 25, 26]
+// [52, 59, 60, 61, 62]
+// [81, 77, 76, 75, 74, 71]
+// [18, 13, 12, 9, 7, 6]
+// [88, 91, 90, 91, 93]
+// [81, 85, 88, 89, 91, 93]
+// [56, 61, 63, 65, 68, 71, 73]
+// [53, 49, 47, 44, 42, 40, 38, 35]
+// [83, 78, 76, 74, 72, 71, 69]
+// [42, 46, 48, 51, 52]
+// [53, 60, 62, 64, 67, 69, 72, 75]
+// [33, 29, 27, 24, 21, 19]
+// [77, 70, 67, 65, 62, 60, 57]
+// [46, 39, 38, 36, 35, 34, 31]
+// [60, 64, 66, 69, 72, 74, 76, 77]
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
+
+    /// `size` reports of 5 to 8 levels, about half of them have a bad level.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let mut out = String::new();
+        for _ in 0..size {
+            let direction = if rng.chance(0.5) { 1 } else { -1 };
+            let mut level = rng.range_i64(30..70);
+            let mut levels = vec![level];
+            for _ in 1..rng.range(5..9) {
+                level += direction * rng.range_i64(1..4);
+                levels.push(level);
+            }
+            if rng.chance(0.5) {
+                let idx = rng.range(0..levels.len());
+                levels[idx] += rng.range_i64(-5..6);
+            }
+            let levels = levels.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+            out.push_str(&levels.join(" "));
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
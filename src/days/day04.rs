@@ -0,0 +1,123 @@
+use crate::error::AocResult;
+use crate::geometry::{Dir8, Point};
+use crate::grid::Grid;
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day04;
+
+type World = Grid<char>;
+
+/// Whether `word` reads from `p` in direction `dir`.
+fn has_word(world: &World, p: Point, dir: Dir8, word: &str) -> bool {
+    let mut p = Some(p);
+    word.chars().all(|ch| match p {
+        Some(current) if world[current] == ch => {
+            p = world.offset(current, dir);
+            true
+        }
+        _ => false,
+    })
+}
+
+fn solve_part1(world: &World) -> i32 {
+    let mut count = 0;
+
+    for p in world.find_all(&'X') {
+        count += Dir8::ALL
+            .into_iter()
+            .filter(|d| has_word(world, p, *d, "XMAS"))
+            .count() as i32;
+    }
+
+    count
+}
+
+fn solve_part2(world: &World) -> i32 {
+    let mut count = 0;
+
+    for p in world.find_all(&'A') {
+        let corner = |dir: Dir8| world.offset(p, dir).map(|x| world[x]);
+        let (Some(top_left), Some(top_right), Some(bottom_left), Some(bottom_right)) = (
+            corner(Dir8::UpLeft),
+            corner(Dir8::UpRight),
+            corner(Dir8::DownLeft),
+            corner(Dir8::DownRight),
+        ) else {
+            // Near the edge, not works.
+            continue;
+        };
+
+        if ((top_left == 'M' && bottom_right == 'S') || (top_left == 'S' && bottom_right == 'M'))
+            && ((top_right == 'M' && bottom_left == 'S')
+                || (top_right == 'S' && bottom_left == 'M'))
+        {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+
+    type Input = World;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input).into())
+    }
+
+    /// Letters of `XMAS` in `size`×`size`.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let mut world = Grid::new(size, size, 'X');
+        for p in world.points().collect::<Vec<_>>() {
+            world[p] = *rng.choose(&['X', 'M', 'A', 'S']);
+        }
+        Ok(world.to_string() + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RawData;
+
+    const INPUT: RawData = r#"MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX"#;
+
+    #[test]
+    fn test_04_01() {
+        assert_eq!(solve_part1(&Day04::parse(INPUT).unwrap()), 18);
+    }
+
+    #[test]
+    fn test_04_02() {
+        assert_eq!(solve_part2(&Day04::parse(INPUT).unwrap()), 9);
+    }
+
+    #[test]
+    fn test_04_non_square() {
+        let world = Day04::parse(&INPUT[..54]).unwrap();
+        assert_eq!((world.width(), world.height()), (10, 5));
+        assert_eq!(solve_part1(&world), 6);
+        assert_eq!(solve_part2(&world), 5);
+    }
+}
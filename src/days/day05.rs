@@ -0,0 +1,202 @@
+use crate::error::AocResult;
+use crate::parse::{lines, literal, map, parse_all, separated, separated_pair, uint, ParseError};
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day05;
+
+type Update = Vec<usize>;
+
+type Input = (Vec<Rule>, Vec<Update>);
+
+#[derive(Debug, Clone)]
+pub struct Rule {
+    before: usize,
+    after: usize,
+}
+
+fn find_mid(update: &Update) -> usize {
+    update[update.len() / 2]
+}
+
+/// Rules and updates are separated by an empty line.
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    // 47|53
+    let rule = map(
+        separated_pair(uint(), literal("|"), uint()),
+        |(before, after)| Rule { before, after },
+    );
+    // 75,47,61,53,29
+    let update = separated(uint(), literal(","));
+    parse_all(
+        separated_pair(lines(rule), literal("\n\n"), lines(update)),
+        input,
+    )
+}
+
+fn solve_part1(input: &Input) -> usize {
+    let (rules, updates) = input;
+
+    fn check_update(update: &Update, rules: &[Rule]) -> bool {
+        for rule in rules {
+            if let (Some(first_idx), Some(second_idx)) = (
+                update.iter().position(|x| *x == rule.before),
+                update.iter().position(|x| *x == rule.after),
+            ) {
+                if first_idx > second_idx {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    let result = updates
+        .iter()
+        .filter_map(|x| {
+            if check_update(x, rules) {
+                Some(find_mid(x))
+            } else {
+                None
+            }
+        })
+        .sum::<usize>();
+
+    result
+}
+
+fn solve_part2(input: &Input) -> usize {
+    let (rules, updates) = input;
+    let mut updates = updates.clone();
+
+    fn fix_updates(update: &mut Update, rules: &[Rule]) -> bool {
+        let mut have_fix = false;
+        // JUST DO IT
+        for round in 1..=20 {
+            for rule in rules {
+                if let (Some(first_idx), Some(second_idx)) = (
+                    update.iter().position(|x| *x == rule.before),
+                    update.iter().position(|x| *x == rule.after),
+                ) {
+                    if first_idx > second_idx {
+                        update.swap(first_idx, second_idx);
+                        if round == 1 {
+                            have_fix = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        have_fix
+    }
+
+    let result = updates
+        .iter_mut()
+        .filter_map(|x| {
+            if fix_updates(x, rules) {
+                Some(find_mid(x))
+            } else {
+                None
+            }
+        })
+        .sum::<usize>();
+
+    result
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = Input;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input).into())
+    }
+
+    /// Rules between every two of 49 pages, and `size` updates of an odd number of pages, about
+    /// half of them in order.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let mut pages = (10..100).collect::<Vec<usize>>();
+        rng.shuffle(&mut pages);
+        pages.truncate(49);
+
+        let mut rules = vec![];
+        for (idx, before) in pages.iter().enumerate() {
+            for after in pages[idx + 1..].iter() {
+                rules.push(format!("{before}|{after}"));
+            }
+        }
+        rng.shuffle(&mut rules);
+
+        let mut updates = vec![];
+        for _ in 0..size {
+            let mut update = pages.clone();
+            rng.shuffle(&mut update);
+            update.truncate(rng.range(2..12) * 2 + 1);
+            if rng.chance(0.5) {
+                update.sort_by_key(|x| pages.iter().position(|page| page == x));
+            }
+            let update = update.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+            updates.push(update.join(","));
+        }
+        Ok(format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RawData;
+
+    const INPUT_RULES: RawData = r#"47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13"#;
+
+    const INPUT_UPDATES: RawData = r#"75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47"#;
+
+    #[test]
+    fn test_05_01() {
+        let input = parse_input(&format!("{INPUT_RULES}\n\n{INPUT_UPDATES}")).unwrap();
+        assert_eq!(solve_part1(&input), 143);
+    }
+
+    #[test]
+    fn test_05_02() {
+        let input = parse_input(&format!("{INPUT_RULES}\n\n{INPUT_UPDATES}")).unwrap();
+        assert_eq!(solve_part2(&input), 123);
+    }
+}
@@ -0,0 +1,317 @@
+use std::ops::Range;
+use std::{collections::HashSet, hash::Hash};
+
+use crate::error::{AocError, AocResult};
+use crate::geometry::{Dir4, Point};
+use crate::grid::Grid;
+use crate::memory;
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day06;
+
+type Position = Point;
+
+type World = Grid<char>;
+
+/// Path describes the routes went before.
+///
+/// If the same [`Path`] occurs twice or more, then we are in a loop.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Path {
+    from_pos: Position,
+    to_pos: Position,
+}
+
+#[derive(Debug)]
+enum Item {
+    Nothing,
+    Something,
+    Edge,
+}
+
+impl Item {
+    fn from_char(ch: &char) -> Self {
+        if ch == &'#' {
+            return Self::Something;
+        }
+        Self::Nothing
+    }
+}
+
+enum StopResult {
+    NotStopped,
+    ReachTheEdge,
+    StuckInLoop,
+}
+
+mod state {
+    use super::*;
+
+    pub struct State {
+        position: Position,
+        direction: Dir4,
+        world: World,
+        paths: Paths,
+    }
+
+    impl State {
+        pub fn new(start_position: Position, world: World) -> Self {
+            Self {
+                position: start_position,
+                direction: Dir4::Up,
+                world,
+                paths: Paths::new(),
+            }
+        }
+
+        pub fn produce_position(&mut self, p: &mut HashSet<Position>) -> StopResult {
+            let pos = self.position;
+            p.insert(pos);
+            match self.get_next_position_item() {
+                Item::Nothing => self.step_forward(),
+                Item::Something => {
+                    // Only record the path when turn direction.
+                    if !self.paths.add_position(pos) {
+                        // We are in a loop
+                        return StopResult::StuckInLoop;
+                    }
+                    self.direction = self.direction.turn_right();
+                    // This is the key of turning direction:
+                    // No one promise we can step forward after turn right.
+                    // Do the next round instead of defaultly step forward.
+                    return StopResult::NotStopped;
+                }
+                Item::Edge => return StopResult::ReachTheEdge,
+            }
+
+            StopResult::NotStopped
+        }
+
+        pub fn position(&self) -> Position {
+            self.position
+        }
+
+        pub fn direction(&self) -> Dir4 {
+            self.direction
+        }
+
+        fn step_forward(&mut self) {
+            if let Some(next) = self.next_position() {
+                self.position = next;
+            }
+        }
+
+        /// Position in front of us, `None` if on the edge.
+        fn next_position(&self) -> Option<Position> {
+            self.world.offset(self.position, self.direction)
+        }
+
+        fn get_next_position_item(&self) -> Item {
+            match self.next_position() {
+                Some(next) => Item::from_char(&self.world[next]),
+                None => Item::Edge,
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct Paths {
+        /// All history paths we went through.
+        paths: HashSet<Path>,
+
+        /// The position of last step, use it when we would produce a new [`Path`].
+        last_pos: Option<Position>,
+    }
+
+    impl Paths {
+        fn new() -> Self {
+            Self {
+                paths: HashSet::new(),
+                last_pos: None,
+            }
+        }
+
+        /// Remember the position and update history path.
+        ///
+        /// Return false is already in a loop (duplicate paths occurred).
+        #[must_use]
+        fn add_position(&mut self, pos: Position) -> bool {
+            if self.last_pos.is_none() {
+                self.last_pos = Some(pos);
+                return true;
+            }
+
+            let path = Path {
+                from_pos: self.last_pos.unwrap(),
+                to_pos: pos,
+            };
+
+            if !self.paths.insert(path) {
+                return false;
+            }
+            self.last_pos = Some(pos);
+
+            true
+        }
+    }
+}
+
+fn find_start(world: &World) -> AocResult<Position> {
+    world
+        .find(&'^')
+        .ok_or_else(|| AocError::invalid("no guard in the map"))
+}
+
+/// Return all points we ever went through.
+///
+/// It is an error if the guard gets stuck in a loop.
+fn solve_part1(world: &World) -> AocResult<HashSet<Position>> {
+    let start_position = find_start(world)?;
+
+    let mut all_poses = HashSet::new();
+    let mut state = state::State::new(start_position, world.clone());
+    loop {
+        match state.produce_position(&mut all_poses) {
+            StopResult::NotStopped => continue,
+            StopResult::ReachTheEdge => return Ok(all_poses),
+            StopResult::StuckInLoop => return Err(AocError::invalid("guard is stuck in a loop")),
+        }
+    }
+}
+
+fn solve_part2(world: &World) -> AocResult<usize> {
+    let mut count = 0;
+
+    let positions = solve_part1(world)?;
+
+    let start_position = find_start(world)?;
+
+    for pos in positions {
+        if world[pos] == '^' {
+            continue;
+        }
+
+        let mut curr_world = world.clone();
+        curr_world[pos] = '#';
+        memory::check_budget()?;
+
+        let all_poses = &mut HashSet::new();
+        let mut state = state::State::new(start_position, curr_world);
+        loop {
+            match state.produce_position(all_poses) {
+                StopResult::NotStopped => continue,
+                StopResult::ReachTheEdge => break,
+                StopResult::StuckInLoop => {
+                    count += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = World;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input)?.len().into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        // 1834 too large.
+        Ok(solve_part2(input)?.into())
+    }
+
+    /// Visited positions are `X`, the guard is an arrow.
+    fn frames(
+        input: &Self::Input,
+        steps: Range<usize>,
+        frame: &mut dyn FnMut(usize, &Grid<char>) -> bool,
+    ) -> AocResult<()> {
+        let mut all_poses = HashSet::new();
+        let mut state = state::State::new(find_start(input)?, input.clone());
+        for step in 0..steps.end {
+            if steps.contains(&step) {
+                let mut world = input.clone();
+                for pos in all_poses.iter() {
+                    world[*pos] = 'X';
+                }
+                world[state.position()] = state.direction().arrow();
+                if !frame(step, &world) {
+                    break;
+                }
+            }
+            if !matches!(
+                state.produce_position(&mut all_poses),
+                StopResult::NotStopped
+            ) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Map of `size`×`size` with a few obstacles, the guard always leaves it.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let size = size.max(1);
+        loop {
+            let mut world = Grid::new(size, size, '.');
+            for p in world.points().collect::<Vec<_>>() {
+                if rng.chance(0.05) {
+                    world[p] = '#';
+                }
+            }
+            world[Point::new(rng.range(0..size), rng.range(0..size))] = '^';
+            if solve_part1(&world).is_ok() {
+                return Ok(world.to_string() + "\n");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RawData;
+
+    const INPUT: RawData = r#"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#..."#;
+
+    #[test]
+    fn test_06_01() {
+        assert_eq!(
+            solve_part1(&Day06::parse(INPUT).unwrap()).unwrap().len(),
+            41
+        );
+    }
+
+    #[test]
+    fn test_06_02() {
+        assert_eq!(solve_part2(&Day06::parse(INPUT).unwrap()).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_06_non_square() {
+        let world = Day06::parse(&INPUT[..77]).unwrap();
+        assert_eq!((world.width(), world.height()), (10, 7));
+        assert_eq!(solve_part1(&world).unwrap().len(), 15);
+    }
+}
@@ -0,0 +1,277 @@
+use crate::checked;
+use crate::error::AocResult;
+use crate::parse::{lines, literal, map, parse_all, separated, separated_pair, uint, ParseError};
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day07;
+
+#[derive(Debug, Clone)]
+pub struct Expr {
+    target: usize,
+    operands: Vec<usize>,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Expr>, ParseError> {
+    // 190: 10 19
+    let expr = map(
+        separated_pair(uint(), literal(": "), separated(uint(), literal(" "))),
+        |(target, operands)| Expr { target, operands },
+    );
+    parse_all(lines(expr), input)
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum FoldResult {
+    /// Acc is more than target value.
+    More,
+
+    /// Acc is less than target value.
+    Less,
+
+    MoreOrLess,
+
+    /// Acc equals target value.
+    Eq,
+}
+
+/// `lhs` followed by the digits of `rhs`, `None` on overflow.
+fn concat(lhs: usize, rhs: usize) -> Option<usize> {
+    let digits = rhs.checked_ilog10().map_or(1, |x| x + 1);
+    lhs.checked_mul(10usize.checked_pow(digits)?)?
+        .checked_add(rhs)
+}
+
+// An operation that overflows gives more than any target, such branches are pruned instead of
+// failing the part.
+
+fn solve_part1(input: &[Expr]) -> AocResult<usize> {
+    let mut sum = 0;
+
+    fn fold_values(target: usize, acc: usize, xs: &[usize]) -> FoldResult {
+        if xs.is_empty() {
+            if target > acc {
+                return FoldResult::Less;
+            } else if target < acc {
+                return FoldResult::More;
+            } else {
+                return FoldResult::Eq;
+            }
+        }
+
+        let x = xs[0];
+
+        let acc_a = acc.checked_add(x);
+
+        // Can not cut this branch because the operands are not sorted.
+        // if target < acc_a {
+        //     return FoldResult::More;
+        // }
+
+        let acc_m = if acc == 0 {
+            Some(x)
+        } else {
+            acc.checked_mul(x)
+        };
+
+        if xs.len() == 1 {
+            if Some(target) == acc_a || Some(target) == acc_m {
+                return FoldResult::Eq;
+            }
+            return FoldResult::MoreOrLess;
+        }
+
+        if let Some(acc_a) = acc_a {
+            if fold_values(target, acc_a, &xs[1..]) == FoldResult::Eq {
+                return FoldResult::Eq;
+            }
+        }
+        // Can not cut this branch because the operands are not sorted.
+        // else if next == FoldResult::More {
+        //     return FoldResult::More;
+        // }
+
+        match acc_m {
+            Some(acc_m) => fold_values(target, acc_m, &xs[1..]),
+            None => FoldResult::More,
+        }
+    }
+
+    for expr in input {
+        let add_value = expr
+            .operands
+            .iter()
+            .try_fold(0usize, |acc, x| acc.checked_add(*x));
+        let mul_value = expr
+            .operands
+            .iter()
+            .try_fold(1usize, |acc, x| acc.checked_mul(*x));
+
+        if add_value == Some(expr.target) || mul_value == Some(expr.target) {
+            sum = checked::add(sum, expr.target)?;
+            continue;
+        }
+
+        // Can not cut this branch because value 1 as operand may break it.
+        // if expr.target < min_value || expr.target > max_value {
+        //     oh = true;
+        // }
+
+        if fold_values(expr.target, 0, expr.operands.as_slice()) == FoldResult::Eq {
+            sum = checked::add(sum, expr.target)?;
+        }
+    }
+    Ok(sum)
+}
+
+fn solve_part2(input: &[Expr]) -> AocResult<usize> {
+    let mut sum = 0;
+
+    fn fold_values(target: usize, acc: usize, xs: &[usize]) -> FoldResult {
+        if xs.is_empty() {
+            if target > acc {
+                return FoldResult::Less;
+            } else if target < acc {
+                return FoldResult::More;
+            } else {
+                return FoldResult::Eq;
+            }
+        }
+
+        let x = xs[0];
+
+        let acc_a = acc.checked_add(x);
+
+        let acc_m = if acc == 0 {
+            Some(x)
+        } else {
+            acc.checked_mul(x)
+        };
+
+        let acc_c = concat(acc, x);
+
+        if xs.len() == 1 {
+            if [acc_a, acc_m, acc_c].contains(&Some(target)) {
+                return FoldResult::Eq;
+            }
+            return FoldResult::MoreOrLess;
+        }
+
+        if let Some(acc_a) = acc_a {
+            if fold_values(target, acc_a, &xs[1..]) == FoldResult::Eq {
+                return FoldResult::Eq;
+            }
+        }
+        // Can not cut this branch because the operands are not sorted.
+        // else if next == FoldResult::More {
+        //     return FoldResult::More;
+        // }
+
+        if let Some(acc_m) = acc_m {
+            if fold_values(target, acc_m, &xs[1..]) == FoldResult::Eq {
+                return FoldResult::Eq;
+            }
+        }
+
+        match acc_c {
+            Some(acc_c) => fold_values(target, acc_c, &xs[1..]),
+            None => FoldResult::More,
+        }
+    }
+
+    for expr in input {
+        if fold_values(expr.target, 0, expr.operands.as_slice()) == FoldResult::Eq {
+            sum = checked::add(sum, expr.target)?;
+        }
+    }
+
+    Ok(sum)
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+
+    type Input = Vec<Expr>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
+
+    /// `size` equations of 2 to 12 operands, about half of them can be made true.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let mut out = String::new();
+        for _ in 0..size {
+            let operands = (0..rng.range(2..13))
+                .map(|_| {
+                    if rng.chance(0.5) {
+                        rng.range(1..10)
+                    } else {
+                        rng.range(10..1000)
+                    }
+                })
+                .collect::<Vec<usize>>();
+            let made = rng.chance(0.5).then(|| {
+                operands[1..]
+                    .iter()
+                    .try_fold(operands[0], |acc, x| match rng.range(0..3) {
+                        0 => acc.checked_add(*x),
+                        1 => acc.checked_mul(*x),
+                        _ => concat(acc, *x),
+                    })
+            });
+            // A made target that overflows is replaced by a random one.
+            let target = match made.flatten() {
+                Some(v) => v,
+                None => rng.range(1..1_000_000_000),
+            };
+            let operands = operands.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+            out.push_str(&format!("{target}: {}\n", operands.join(" ")));
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RawData;
+
+    const INPUT: RawData = r#"190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20"#;
+
+    #[test]
+    fn test_07_01() {
+        assert_eq!(solve_part1(&parse_input(INPUT).unwrap()).unwrap(), 3749);
+    }
+
+    #[test]
+    fn test_07_02() {
+        assert_eq!(solve_part2(&parse_input(INPUT).unwrap()).unwrap(), 11387);
+    }
+
+    #[test]
+    fn test_overflowing_branch() {
+        let input = parse_input("190: 10 19\n100: 999 999 999 999 999 999 999").unwrap();
+        assert_eq!(solve_part1(&input).unwrap(), 190);
+        assert_eq!(solve_part2(&input).unwrap(), 190);
+
+        let input = parse_input("1: 99999999999 99999999999").unwrap();
+        assert_eq!(solve_part2(&input).unwrap(), 0);
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::AocResult;
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day08;
+
+type World = Grid<char>;
+
+type Freq = char;
+
+fn get_aninodes(world: &World, pos1: Point, pos2: Point, extend: bool) -> Vec<Point> {
+    let mut nodes = vec![];
+
+    for (from, other) in [(pos1, pos2), (pos2, pos1)] {
+        let step = from.to_signed() - other.to_signed();
+        let mut level = if extend { 0 } else { 1 };
+        while let Some(pos) = world.offset(from, step * level) {
+            nodes.push(pos);
+            level += 1;
+
+            if !extend {
+                break;
+            }
+        }
+    }
+
+    nodes
+}
+
+fn solve(world: &World, extend: bool) -> usize {
+    let mut freq_map: HashMap<Freq, Vec<Point>> = HashMap::new();
+
+    for (p, freq) in world.iter() {
+        if freq == &'.' || freq == &'#' {
+            // Empty
+            continue;
+        }
+
+        freq_map.entry(*freq).or_default().push(p);
+    }
+
+    let mut nodes = HashSet::<Point>::new();
+
+    for (_, poses) in freq_map.iter() {
+        for pos1 in poses {
+            for pos2 in poses {
+                if pos1 == pos2 {
+                    continue;
+                }
+                nodes.extend(get_aninodes(world, *pos1, *pos2, extend));
+            }
+        }
+    }
+
+    nodes.len()
+}
+
+fn solve_part1(world: &World) -> usize {
+    solve(world, false)
+}
+
+fn solve_part2(world: &World) -> usize {
+    solve(world, true)
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+
+    type Input = World;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input).into())
+    }
+
+    /// Map of `size`×`size` with antennas, about four of each frequency.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        const FREQUENCIES: &[u8] =
+            b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let mut world = Grid::new(size, size, '.');
+        let antennas = size * size / 40;
+        let frequencies = &FREQUENCIES[..(antennas / 4).clamp(1, FREQUENCIES.len())];
+        for _ in 0..antennas {
+            let p = Point::new(rng.range(0..size), rng.range(0..size));
+            world[p] = *rng.choose(frequencies) as char;
+        }
+        Ok(world.to_string() + "\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RawData;
+
+    const INPUT: RawData = r#"......#....#
+...#....0...
+....#0....#.
+..#....0....
+....0....#..
+.#....A.....
+...#........
+#......#....
+........A...
+.........A..
+..........#.
+..........#."#;
+
+    #[test]
+    fn test_08_01() {
+        assert_eq!(solve_part1(&Day08::parse(INPUT).unwrap()), 14);
+    }
+
+    #[test]
+    fn test_08_02() {
+        assert_eq!(solve_part2(&Day08::parse(INPUT).unwrap()), 34);
+    }
+}
@@ -0,0 +1,271 @@
+use crate::checked;
+use crate::error::{AocError, AocResult};
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day09;
+
+type Block = Option<usize>;
+
+fn pretty_disk(disk: &[Block]) -> String {
+    disk.iter()
+        .map(|x| match x {
+            Some(v) => v.to_string(),
+            None => String::from("."),
+        })
+        .collect::<String>()
+}
+
+/// Blocks of the disk, with at least one file block.
+fn expand_disk(input: &str) -> AocResult<Vec<Block>> {
+    let mut out = vec![];
+
+    let mut scanning_file = true;
+
+    let mut file_id = 0;
+
+    for (offset, ch) in input.char_indices() {
+        let Some(len) = ch.to_digit(10) else {
+            return Err(ParseError::at(input, offset, "expected digit").into());
+        };
+        if scanning_file {
+            let mut file = vec![Some(file_id); len as usize];
+            out.append(&mut file);
+            scanning_file = false;
+            file_id += 1;
+        } else {
+            let mut empty_block_list = vec![None; len as usize];
+            out.append(&mut empty_block_list);
+            scanning_file = true;
+        }
+    }
+
+    if out.iter().all(|x| x.is_none()) {
+        return Err(AocError::invalid("disk has no file blocks"));
+    }
+    Ok(out)
+}
+
+fn solve_part1(input: &str) -> AocResult<usize> {
+    let mut disk = expand_disk(input)?;
+
+    let mut pos = 0;
+    let mut rpos = disk.len() - 1;
+
+    loop {
+        if pos >= rpos {
+            break;
+        }
+
+        // pos next.
+        let Some(empty_block_idx) = disk.iter().position(|x| x.is_none()) else {
+            break;
+        };
+
+        // rpos next.
+        let file_block_idx = disk.iter().rposition(|x| x.is_some()).unwrap();
+
+        if empty_block_idx >= file_block_idx {
+            break;
+        }
+
+        disk[empty_block_idx] = disk[file_block_idx];
+        disk[file_block_idx] = None;
+
+        pos = empty_block_idx;
+        rpos = file_block_idx;
+    }
+
+    let mut sum = 0;
+
+    crate::debug!("disk after compact: {}", pretty_disk(&disk));
+
+    for (idx, block) in disk.iter().enumerate() {
+        if block.is_none() {
+            break;
+        }
+        sum = checked::add(sum, checked::mul(idx, block.unwrap())?)?;
+    }
+
+    Ok(sum)
+}
+
+fn solve_part2(input: &str) -> AocResult<usize> {
+    let mut disk = expand_disk(input)?;
+
+    let mut rpos = 0;
+
+    crate::debug!("disk before compact: {}", pretty_disk(&disk));
+
+    // Get next file to move.
+    // rpos next.
+    while let Some(file_block_skip) = disk.iter().rev().skip(rpos).position(|x| x.is_some()) {
+        let file_block_right_pos = disk.len() - 1 - rpos - file_block_skip;
+        let file_id = disk[file_block_right_pos];
+        let file_block_offset = match disk
+            .iter()
+            .rev()
+            .skip(disk.len() - 1 - file_block_right_pos)
+            .position(|x| *x != file_id)
+        {
+            None => break,
+            Some(v) => v,
+        };
+        let file_block_left_pos = file_block_right_pos - file_block_offset + 1;
+        crate::trace!("file_id={file_id:?} {file_block_left_pos}..={file_block_right_pos}");
+
+        // Searching position in current file block round.
+        let mut initial_pos = 0;
+        // Search next suitable space.
+        // empty block start position.
+        let mut empty_block_left_pos = None;
+        loop {
+            let p = initial_pos
+                + disk
+                    .iter()
+                    .skip(initial_pos)
+                    .position(|x| x.is_none())
+                    .unwrap_or(disk.len());
+            if p >= file_block_right_pos {
+                crate::trace!("no space for file {file_block_left_pos}..={file_block_right_pos}");
+                break;
+            }
+            // empty block end position, exclusive.
+            let empty_block_offset = match disk.iter().skip(p).position(|x| x.is_some()) {
+                None => break,
+                Some(v) => v,
+            };
+            if empty_block_offset < file_block_offset {
+                // The position has not enough space.
+                initial_pos = p + empty_block_offset + 1;
+                crate::trace!("not enough space: {empty_block_offset} < {file_block_offset}");
+                continue;
+            }
+
+            empty_block_left_pos = Some(p);
+            break;
+        }
+
+        if empty_block_left_pos.is_none() {
+            rpos = disk.len() - 1 - (file_block_left_pos - 1);
+            crate::trace!("skip file_id={file_id:?}, {file_block_left_pos}..={file_block_right_pos}, rpos={rpos}");
+            continue;
+        }
+
+        let empty_block_left_pos = empty_block_left_pos.unwrap();
+
+        if empty_block_left_pos >= disk.len() - 1 - rpos {
+            // No suitable space for current file.
+            rpos = disk.len() - 1 - (file_block_left_pos - 1);
+            crate::trace!("skip file_id={file_id:?} without space on the left, {file_block_left_pos}..={file_block_right_pos}, rpos={rpos}");
+            continue;
+        }
+
+        let empty_block_right_pos = empty_block_left_pos + file_block_offset - 1;
+
+        // Can move.
+
+        crate::trace!("move: {empty_block_left_pos}..={empty_block_right_pos} <- {file_block_left_pos}..={file_block_right_pos}");
+        disk.splice(
+            empty_block_left_pos..=empty_block_right_pos,
+            vec![file_id; file_block_offset],
+        );
+        disk.splice(
+            file_block_left_pos..=file_block_right_pos,
+            vec![None; file_block_offset],
+        );
+        crate::trace!("disk after move: {}", pretty_disk(&disk));
+
+        rpos = disk.len() - 1 - (file_block_left_pos - 1);
+
+        if rpos == disk.len() - 1 {
+            break;
+        }
+    }
+
+    let mut sum = 0;
+
+    crate::debug!("disk after compact: {}", pretty_disk(&disk));
+
+    for (idx, block) in disk.iter().enumerate() {
+        if block.is_none() {
+            continue;
+        }
+        sum = checked::add(sum, checked::mul(idx, block.unwrap())?)?;
+    }
+
+    Ok(sum)
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+
+    type Input = String;
+
+    /// The disk map is a line of digits.
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        let disk_map = input.trim_end();
+        if let Some(offset) = disk_map.find(|c: char| !c.is_ascii_digit()) {
+            return Err(ParseError::at(input, offset, "expected digit").into());
+        }
+        Ok(disk_map.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
+
+    /// Disk map of `size` files of 1 to 9 blocks, with 0 to 9 free blocks between them.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let mut out = String::new();
+        for idx in 0..size.max(1) {
+            if idx > 0 {
+                out.push_str(&rng.range(0..10).to_string());
+            }
+            out.push_str(&rng.range(1..10).to_string());
+        }
+        out.push('\n');
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RawData;
+
+    const INPUT: RawData = r#"2333133121414131402"#;
+
+    #[test]
+    fn test_expand_disk() {
+        assert_eq!(
+            pretty_disk(&expand_disk(INPUT).unwrap()).as_str(),
+            "00...111...2...333.44.5555.6666.777.888899"
+        );
+    }
+
+    #[test]
+    fn test_09_01() {
+        assert_eq!(solve_part1(INPUT).unwrap(), 1928);
+    }
+
+    #[test]
+    fn test_09_02() {
+        assert_eq!(solve_part2(INPUT).unwrap(), 2858);
+    }
+
+    #[test]
+    fn test_invalid_disk() {
+        assert!(Day09::parse("").and_then(|x| Day09::part1(&x)).is_err());
+        assert!(solve_part1("0").is_err());
+        assert!(solve_part2("05").is_err());
+        assert_eq!(solve_part1("9").unwrap(), 0);
+        assert_eq!(solve_part2("9").unwrap(), 0);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::checked;
+use crate::error::AocResult;
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::search::{bfs, Paths};
+use crate::{Answer, Solution};
+
+pub struct Day10;
+
+type World = Grid<char>;
+
+fn parse_world(input: &str) -> Result<World, ParseError> {
+    Grid::parse(input)
+}
+
+/// All hiking trails from the trailhead `start`, a trail goes up by exactly 1 each step.
+fn trails(world: &World, start: Point) -> Paths<Point> {
+    // Heights are compared as `u32`, a `char` is at most 0x10FFFF, so going up never overflows.
+    bfs([start], |p: &Point| {
+        let height = u32::from(world[*p]);
+        world
+            .neighbours4(*p)
+            .filter(move |next| u32::from(world[*next]) == height + 1)
+    })
+}
+
+/// Sum of `score` over all trailheads, `score` is given the trails and the reachable 9s.
+fn sum_trailheads(
+    world: &World,
+    score: impl Fn(&Paths<Point>, &[Point]) -> AocResult<usize>,
+) -> AocResult<usize> {
+    world.find_all(&'0').try_fold(0, |acc, start| {
+        let paths = trails(world, start);
+        let ends = world
+            .find_all(&'9')
+            .filter(|p| paths.distance(p).is_some())
+            .collect::<Vec<_>>();
+        checked::add(acc, score(&paths, &ends)?)
+    })
+}
+
+fn solve_part1(world: &World) -> AocResult<usize> {
+    sum_trailheads(world, |_, ends| Ok(ends.len()))
+}
+
+fn solve_part2(world: &World) -> AocResult<usize> {
+    // All trails are of the same length, so every trail is a best path.
+    sum_trailheads(world, |paths, ends| {
+        checked::sum(ends.iter().map(|p| paths.count_paths(p)))
+    })
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+
+    type Input = World;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_world(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
+
+    /// Map of `size`×`size`, heights go down from a few peaks so that there are trails.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let size = size.max(1);
+        let peaks = (0..(size * size / 64).max(1))
+            .map(|_| Point::new(rng.range(0..size), rng.range(0..size)))
+            .collect::<Vec<_>>();
+        let mut world = Grid::new(size, size, '0');
+        for p in world.points().collect::<Vec<_>>() {
+            let distance = peaks.iter().map(|x| x.manhattan(p)).min().unwrap_or(0);
+            let height = if rng.chance(0.1) {
+                rng.range(0..10)
+            } else {
+                9 - distance.min(9)
+            };
+            world[p] = char::from(b'0' + height as u8);
+        }
+        Ok(world.to_string() + "\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RawData;
+
+    #[rustfmt::skip]
+    const INPUT: RawData =
+"\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn test_10_01() {
+        assert_eq!(solve_part1(&parse_world(INPUT).unwrap()).unwrap(), 36);
+    }
+
+    #[test]
+    fn test_10_02() {
+        assert_eq!(solve_part2(&parse_world(INPUT).unwrap()).unwrap(), 81);
+    }
+}
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+
+use crate::checked;
+use crate::error::AocResult;
+use crate::parse::{parse_all, separated, spaces, uint, ParseError};
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day11;
+
+type Stone = usize;
+
+type StoneLine = Vec<Stone>;
+
+fn should_split(stone: &Stone) -> Option<(Stone, Stone)> {
+    let digits = stone.checked_ilog10()? + 1;
+
+    if digits % 2 == 0 {
+        let half = Stone::pow(10, digits / 2);
+        Some((stone / half, stone % half))
+    } else {
+        None
+    }
+}
+
+fn blink(stone_line: &mut StoneLine) -> AocResult<()> {
+    let mut pos = 0;
+    while pos < stone_line.len() {
+        let stone = &mut stone_line[pos];
+
+        if *stone == 0 {
+            *stone = 1;
+            pos += 1;
+            continue;
+        }
+
+        if let Some((left, right)) = should_split(stone) {
+            *stone = left;
+            stone_line.insert(pos + 1, right);
+            pos += 2;
+            continue;
+        }
+
+        *stone = checked::mul(*stone, 2024)?;
+        pos += 1;
+    }
+    Ok(())
+}
+
+/// This solution not works.
+///
+/// The complexity is too large because we still save the result of each round.
+///
+/// It's only 1 times faster than the original [blink] solution, not enough for 75 rounds.
+#[allow(dead_code)]
+fn blink_ex(stone_line: StoneLine, round: usize) -> usize {
+    #[derive(Debug)]
+    struct StoneEx {
+        data: usize,
+        round: usize,
+    }
+
+    impl StoneEx {
+        fn still_blinking(&self) -> bool {
+            self.round > 0
+        }
+
+        fn blink(&mut self) -> Option<StoneEx> {
+            if !self.still_blinking() {
+                return None;
+            }
+
+            self.round -= 1;
+
+            if self.data == 0 {
+                self.data = 1;
+                None
+            } else if let Some((left, right)) = should_split(&self.data) {
+                self.data = left;
+                Some(StoneEx {
+                    data: right,
+                    round: self.round,
+                })
+            } else {
+                self.data *= 2024;
+                None
+            }
+        }
+    }
+
+    let mut blinking_stones = stone_line
+        .into_iter()
+        .map(|data| StoneEx { data, round })
+        .collect::<Vec<_>>();
+
+    let mut count = 0;
+
+    while !blinking_stones.is_empty() {
+        let mut birthed_stones = vec![];
+
+        for stone in blinking_stones.iter_mut() {
+            if let Some(s) = stone.blink() {
+                birthed_stones.push(s);
+            }
+        }
+
+        let c1 = blinking_stones.len();
+        blinking_stones.retain(|x| x.still_blinking());
+        let c2 = blinking_stones.len();
+        count += c1 - c2;
+
+        blinking_stones.extend(birthed_stones);
+    }
+
+    count
+}
+
+/// This solution works.
+///
+/// Same numbers are calculated once, use a hash map to store the counts of each number.
+fn blink_ex2(stone_line: StoneLine, round: usize) -> AocResult<usize> {
+    fn add_or_insert(m: &mut HashMap<Stone, usize>, stone: Stone, value: usize) -> AocResult<()> {
+        match m.get_mut(&stone) {
+            Some(v) => *v = checked::add(*v, value)?,
+            None => {
+                m.insert(stone, value);
+            }
+        }
+        Ok(())
+    }
+
+    let mut round_result = HashMap::<Stone, usize>::new();
+    for stone in stone_line {
+        add_or_insert(&mut round_result, stone, 1)?;
+    }
+
+    fn blink_round(stones: &HashMap<Stone, usize>) -> AocResult<HashMap<Stone, usize>> {
+        let stone_keys = stones.keys().map(|x| x.to_owned()).collect::<Vec<_>>();
+        let mut calculated = HashMap::new();
+        for stone in stone_keys {
+            let stone_count = stones[&stone];
+            if stone == 0 {
+                add_or_insert(&mut calculated, 1, stone_count)?;
+                continue;
+            }
+
+            if let Some((left, right)) = should_split(&stone) {
+                add_or_insert(&mut calculated, left, stone_count)?;
+                add_or_insert(&mut calculated, right, stone_count)?;
+                continue;
+            }
+
+            add_or_insert(&mut calculated, checked::mul(stone, 2024)?, stone_count)?;
+        }
+
+        Ok(calculated)
+    }
+
+    for _round in 0..round {
+        round_result = blink_round(&round_result)?;
+    }
+    checked::sum(round_result.values().copied())
+}
+
+fn parse_input(input: &str) -> Result<StoneLine, ParseError> {
+    parse_all(separated(uint(), spaces()), input)
+}
+
+fn solve_01(stone_line: &StoneLine) -> AocResult<usize> {
+    let mut stone_line = stone_line.clone();
+
+    for _round in 0..25 {
+        blink(&mut stone_line)?;
+    }
+
+    Ok(stone_line.len())
+}
+
+fn solve_02(stone_line: &StoneLine) -> AocResult<usize> {
+    // blink_ex(stone_line, 25)
+    blink_ex2(stone_line.clone(), 75)
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+
+    type Input = StoneLine;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_01(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_02(input)?.into())
+    }
+
+    /// `size` stones of up to 7 digits.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let stones = (0..size.max(1))
+            .map(|_| rng.range(0..10_000_000).to_string())
+            .collect::<Vec<_>>();
+        Ok(stones.join(" ") + "\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::differential::{self, Config};
+    use crate::RawData;
+
+    const INPUT: RawData = "125 17";
+
+    #[test]
+    fn test_split() {
+        assert_eq!(should_split(&0), None);
+        assert_eq!(should_split(&1), None);
+        assert_eq!(should_split(&2), None);
+        assert_eq!(should_split(&10), Some((1, 0)));
+        assert_eq!(should_split(&100), None);
+        assert_eq!(should_split(&1000), Some((10, 0)));
+        assert_eq!(should_split(&123456), Some((123, 456)));
+    }
+
+    #[test]
+    fn test_no_stones() {
+        let err = parse_input("").unwrap_err();
+        assert!(
+            err.to_string().contains("expected unsigned integer"),
+            "{err}"
+        );
+        assert!(parse_input("\n").is_err());
+
+        let mut stone_line = vec![];
+        blink(&mut stone_line).unwrap();
+        assert!(stone_line.is_empty());
+    }
+
+    #[test]
+    fn test_11_01() {
+        assert_eq!(solve_01(&parse_input(INPUT).unwrap()).unwrap(), 55312);
+    }
+
+    #[test]
+    fn test_blink_ex() {
+        let stone_line: StoneLine = INPUT.split(" ").map(|x| x.parse().unwrap()).collect();
+        // assert_eq!(blink_ex(stone_line.clone(), 25), 55312);
+        assert_eq!(blink_ex2(stone_line, 25).unwrap(), 55312);
+    }
+
+    #[test]
+    fn test_blink_differential() {
+        differential::check(
+            &Config::default(),
+            |rng| {
+                let stones = (0..rng.range(1..6))
+                    .map(|_| rng.range(0..1_000_000))
+                    .collect::<StoneLine>();
+                (stones, rng.range(0..15))
+            },
+            |(stones, rounds)| {
+                let mut stone_line = stones.clone();
+                for _round in 0..*rounds {
+                    blink(&mut stone_line).unwrap();
+                }
+                stone_line.len()
+            },
+            |(stones, rounds)| blink_ex2(stones.clone(), *rounds).unwrap(),
+        );
+    }
+}
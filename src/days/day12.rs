@@ -0,0 +1,141 @@
+use crate::components::{label_eq, Components};
+use crate::error::AocResult;
+use crate::geometry::{Dir4, IPoint, Point};
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day12;
+
+type World = Grid<char>;
+
+fn parse_world(input: &str) -> Result<World, ParseError> {
+    Grid::parse(input)
+}
+
+/// Whether the cell at `offset` from `p` is in the same region as `p`.
+fn same_region(regions: &Components, p: Point, offset: impl Into<IPoint>) -> bool {
+    regions
+        .labels()
+        .offset(p, offset)
+        .is_some_and(|q| regions.label(q) == regions.label(p))
+}
+
+/// Fences around `p`, one on each side facing another region or outside.
+fn fences(regions: &Components, p: Point) -> usize {
+    Dir4::ALL
+        .into_iter()
+        .filter(|dir| !same_region(regions, p, *dir))
+        .count()
+}
+
+/// Corners of the region at `p`, a region has as many sides as corners.
+fn corners(regions: &Components, p: Point) -> usize {
+    Dir4::ALL
+        .into_iter()
+        .filter(|dir| {
+            let side = dir.turn_right();
+            let a = same_region(regions, p, *dir);
+            let b = same_region(regions, p, side);
+            let diagonal = same_region(regions, p, IPoint::from(*dir) + IPoint::from(side));
+            // Outer corner, or inner corner.
+            (!a && !b) || (a && b && !diagonal)
+        })
+        .count()
+}
+
+/// Sum of area times `measure` summed over cells of every region.
+fn total_price(world: &World, measure: impl Fn(&Components, Point) -> usize) -> usize {
+    let regions = label_eq(world);
+    regions
+        .iter()
+        .map(|cells| cells.len() * cells.iter().map(|p| measure(&regions, *p)).sum::<usize>())
+        .sum()
+}
+
+fn solve_part1(world: &World) -> usize {
+    total_price(world, fences)
+}
+
+fn solve_part2(world: &World) -> usize {
+    total_price(world, corners)
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Garden Groups";
+
+    type Input = World;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_world(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input).into())
+    }
+
+    /// Map of `size`×`size`, each plant grows around a few seeds.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let size = size.max(1);
+        let seeds = (0..(size * size / 16).max(1))
+            .map(|_| {
+                let p = Point::new(rng.range(0..size), rng.range(0..size));
+                (p, char::from(b'A' + rng.range(0..26) as u8))
+            })
+            .collect::<Vec<_>>();
+        let mut world = Grid::new(size, size, 'A');
+        for p in world.points().collect::<Vec<_>>() {
+            if let Some((_, plant)) = seeds.iter().min_by_key(|(seed, _)| seed.manhattan(p)) {
+                world[p] = *plant;
+            }
+        }
+        Ok(world.to_string() + "\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RawData;
+
+    #[rustfmt::skip]
+    const INPUT: RawData = //
+"\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    #[test]
+    fn test_12_01() {
+        assert_eq!(solve_part1(&parse_world(INPUT).unwrap()), 1930);
+    }
+
+    #[test]
+    fn test_12_02() {
+        assert_eq!(solve_part2(&parse_world(INPUT).unwrap()), 1206);
+    }
+
+    #[test]
+    fn test_sides() {
+        let world = parse_world("EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE").unwrap();
+        assert_eq!(solve_part2(&world), 236);
+
+        // The inner B regions touch at a corner, the A region around them has an inner corner
+        // on either side of that point.
+        let world = parse_world("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA").unwrap();
+        assert_eq!(solve_part2(&world), 368);
+    }
+}
@@ -0,0 +1,381 @@
+use crate::checked;
+use crate::error::AocResult;
+use crate::parse::{
+    blocks, field, literal, map, parse_all, preceded, separated_pair, uint, ParseError,
+};
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day13;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Offset {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Button {
+    offset: Offset,
+    price: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClawMachine {
+    button_a: Button,
+
+    button_b: Button,
+
+    target: Offset,
+}
+
+impl ClawMachine {
+    fn calculate_cost(&self) -> Option<usize> {
+        let mut costs = vec![];
+
+        for a_times in 0..=100 {
+            for b_times in 0..=100 {
+                if self.button_a.offset.x * a_times + self.button_b.offset.x * b_times
+                    == self.target.x
+                    && self.button_a.offset.y * a_times + self.button_b.offset.y * b_times
+                        == self.target.y
+                {
+                    costs.push(self.button_a.price * a_times + self.button_b.price * b_times);
+                }
+            }
+        }
+
+        costs.iter().min().map(|x| x.to_owned())
+    }
+
+    /// Solve the two linear equations, buttons are never parallel in puzzle inputs.
+    ///
+    /// Parallel buttons have many or no solutions, those go to [`Self::cheapest_on_line`].
+    fn calculate_cost_ex(&self) -> AocResult<Option<usize>> {
+        use checked::{add, mul, sub};
+
+        let (a, b) = (&self.button_a.offset, &self.button_b.offset);
+        let [ax, ay, bx, by, tx, ty] =
+            [a.x, a.y, b.x, b.y, self.target.x, self.target.y].map(|v| v as i64);
+        let determinant = sub(mul(ax, by)?, mul(bx, ay)?)?;
+        if determinant == 0 {
+            crate::debug!("parallel buttons, solving along their line");
+            return self.cheapest_on_line();
+        }
+        // Either may be negative.
+        let x = sub(mul(tx, by)?, mul(bx, ty)?)? / determinant;
+        let y = sub(mul(ax, ty)?, mul(ay, tx)?)? / determinant;
+
+        if x >= 0
+            && y >= 0
+            && add(mul(ax, x)?, mul(bx, y)?)? == tx
+            && add(mul(ay, x)?, mul(by, y)?)? == ty
+        {
+            crate::debug!("solved with a={x} b={y}");
+            let cost_a = mul(self.button_a.price, x as usize)?;
+            let cost_b = mul(self.button_b.price, y as usize)?;
+            Ok(Some(add(cost_a, cost_b)?))
+        } else {
+            crate::debug!("no integer solution near a={x} b={y}");
+            Ok(None)
+        }
+    }
+
+    /// Cheapest presses of parallel buttons, any number of times.
+    ///
+    /// Both buttons move along one line, so only one axis is solved: `a * p + b * q = t` with
+    /// `p, q >= 0`. Solutions are `p0 + k * b / g, q0 - k * a / g` for `g = gcd(a, b)`, the
+    /// cost changes linearly with `k`, so the cheapest is either the fewest A or the fewest B
+    /// presses. Both are checked against the other axis, which fails for a target off the line.
+    fn cheapest_on_line(&self) -> AocResult<Option<usize>> {
+        let (a, b, t) = (&self.button_a.offset, &self.button_b.offset, &self.target);
+        let (a, b, t) = if a.x > 0 || b.x > 0 {
+            (a.x as i128, b.x as i128, t.x as i128)
+        } else {
+            (a.y as i128, b.y as i128, t.y as i128)
+        };
+
+        let candidates = match (a, b) {
+            (0, 0) => vec![(0, 0)],
+            (0, _) => vec![(0, t / b)],
+            (_, 0) => vec![(t / a, 0)],
+            _ => {
+                let (g, x, y) = extended_gcd(a, b);
+                if t % g != 0 {
+                    return Ok(None);
+                }
+                let fewest_a = (x * (t / g)).rem_euclid(b / g);
+                let fewest_b = (y * (t / g)).rem_euclid(a / g);
+                vec![
+                    (fewest_a, (t - a * fewest_a) / b),
+                    ((t - b * fewest_b) / a, fewest_b),
+                ]
+            }
+        };
+
+        let mut best = None;
+        for (p, q) in candidates {
+            let (Ok(p), Ok(q)) = (usize::try_from(p), usize::try_from(q)) else {
+                continue;
+            };
+            if !self.reached_by(p, q)? {
+                continue;
+            }
+            let cost = checked::add(
+                checked::mul(self.button_a.price, p)?,
+                checked::mul(self.button_b.price, q)?,
+            )?;
+            crate::debug!("solved with a={p} b={q} on the line");
+            best = Some(best.map_or(cost, |best: usize| best.min(cost)));
+        }
+        Ok(best)
+    }
+
+    /// Whether pressing A `p` times and B `q` times reaches the target.
+    fn reached_by(&self, p: usize, q: usize) -> AocResult<bool> {
+        use checked::{add, mul};
+
+        let (a, b) = (&self.button_a.offset, &self.button_b.offset);
+        Ok(add(mul(a.x, p)?, mul(b.x, q)?)? == self.target.x
+            && add(mul(a.y, p)?, mul(b.y, q)?)? == self.target.y)
+    }
+
+    fn far_more(&mut self) -> AocResult<&mut Self> {
+        self.target.x = checked::add(self.target.x, 10000000000000)?;
+        self.target.y = checked::add(self.target.y, 10000000000000)?;
+        Ok(self)
+    }
+}
+
+/// `(g, x, y)` with `a * x + b * y = g = gcd(a, b)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+/// Random machine like in puzzle inputs, buttons are never parallel.
+fn random_machine(rng: &mut Rng) -> ClawMachine {
+    let mut button = |price| Button {
+        offset: Offset {
+            x: rng.range(10..100),
+            y: rng.range(10..100),
+        },
+        price,
+    };
+    let (button_a, button_b) = loop {
+        let (a, b) = (button(3), button(1));
+        if a.offset.x * b.offset.y != b.offset.x * a.offset.y {
+            break (a, b);
+        }
+    };
+    let target = if rng.chance(0.5) {
+        let (a_times, b_times) = (rng.range(0..101), rng.range(0..101));
+        Offset {
+            x: button_a.offset.x * a_times + button_b.offset.x * b_times,
+            y: button_a.offset.y * a_times + button_b.offset.y * b_times,
+        }
+    } else {
+        Offset {
+            x: rng.range(0..20000),
+            y: rng.range(0..20000),
+        }
+    };
+    ClawMachine {
+        button_a,
+        button_b,
+        target,
+    }
+}
+
+fn parse_machines(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    // Button A: X+94, Y+34
+    let button = |name, price| {
+        map(
+            preceded(
+                literal(name),
+                separated_pair(
+                    preceded(literal("X+"), uint()),
+                    literal(", "),
+                    preceded(literal("Y+"), uint()),
+                ),
+            ),
+            move |(x, y)| Button {
+                offset: Offset { x, y },
+                price,
+            },
+        )
+    };
+    // Prize: X=8400, Y=5400
+    let prize = map(
+        preceded(
+            literal("Prize: "),
+            separated_pair(field("X", uint()), literal(", "), field("Y", uint())),
+        ),
+        |(x, y)| Offset { x, y },
+    );
+    let machine = map(
+        separated_pair(
+            button("Button A: ", 3),
+            literal("\n"),
+            separated_pair(button("Button B: ", 1), literal("\n"), prize),
+        ),
+        |(button_a, (button_b, target))| ClawMachine {
+            button_a,
+            button_b,
+            target,
+        },
+    );
+    parse_all(blocks(machine), input)
+}
+
+fn solve_part1(machines: &[ClawMachine]) -> AocResult<usize> {
+    checked::sum(machines.iter().filter_map(|x| x.calculate_cost()))
+}
+
+fn solve_part2(machines: &[ClawMachine]) -> AocResult<usize> {
+    let mut sum = 0;
+    for machine in machines.to_vec().iter_mut() {
+        if let Some(cost) = machine.far_more()?.calculate_cost_ex()? {
+            sum = checked::add(sum, cost)?;
+        }
+    }
+    Ok(sum)
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Claw Contraption";
+
+    type Input = Vec<ClawMachine>;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_machines(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
+
+    /// `size` machines, about half of them can be won in 100 presses of each button.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let machines = (0..size.max(1))
+            .map(|_| {
+                let machine = random_machine(rng);
+                let (a, b, target) = (
+                    &machine.button_a.offset,
+                    &machine.button_b.offset,
+                    &machine.target,
+                );
+                format!(
+                    "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+                    a.x, a.y, b.x, b.y, target.x, target.y
+                )
+            })
+            .collect::<Vec<_>>();
+        Ok(machines.join("\n\n") + "\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::differential::{self, Config};
+    use crate::RawData;
+
+    const INPUT: RawData = r#"Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279"#;
+
+    /// Machines won in 100 presses of each button, the brute force finds those as well.
+    fn reachable_machine(rng: &mut Rng) -> ClawMachine {
+        let mut machine = random_machine(rng);
+        let (a, b) = (&machine.button_a.offset, &machine.button_b.offset);
+        let (a_times, b_times) = (rng.range(0..101), rng.range(0..101));
+        machine.target = Offset {
+            x: a.x * a_times + b.x * b_times,
+            y: a.y * a_times + b.y * b_times,
+        };
+        machine
+    }
+
+    #[test]
+    fn test_cost_differential() {
+        differential::check(
+            &Config::default(),
+            reachable_machine,
+            |machine| machine.calculate_cost(),
+            |machine| machine.calculate_cost_ex().unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_degenerate_buttons() {
+        let machines = parse_machines(
+            "Button A: X+2, Y+2\nButton B: X+1, Y+1\nPrize: X=10, Y=10\n\n\
+             Button A: X+3, Y+5\nButton B: X+4, Y+0\nPrize: X=17, Y=15",
+        )
+        .unwrap();
+        assert_eq!(machines[0].calculate_cost_ex().unwrap(), Some(10));
+        assert_eq!(machines[1].calculate_cost_ex().unwrap(), Some(11));
+    }
+
+    #[test]
+    fn test_parallel_buttons_far_more() {
+        let mut machines = parse_machines(
+            "Button A: X+4, Y+4\nButton B: X+1, Y+1\nPrize: X=10, Y=10\n\n\
+             Button A: X+2, Y+4\nButton B: X+4, Y+8\nPrize: X=11, Y=22\n\n\
+             Button A: X+2, Y+2\nButton B: X+3, Y+3\nPrize: X=10, Y=11\n\n\
+             Button A: X+6, Y+0\nButton B: X+9, Y+0\nPrize: X=6, Y=0",
+        )
+        .unwrap();
+        for machine in machines.iter_mut() {
+            machine.far_more().unwrap();
+        }
+        // A moves 4 for 3 tokens, as much A as possible and B for the rest of 10^13 + 10.
+        assert_eq!(
+            machines[0].calculate_cost_ex().unwrap(),
+            Some(2500000000002 * 3 + 2)
+        );
+        // Only even moves.
+        assert_eq!(machines[1].calculate_cost_ex().unwrap(), None);
+        // Off the line.
+        assert_eq!(machines[2].calculate_cost_ex().unwrap(), None);
+        // No way to move up.
+        assert_eq!(machines[3].calculate_cost_ex().unwrap(), None);
+
+        // B moves 3 for 1 token, all B.
+        let machine =
+            &mut parse_machines("Button A: X+2, Y+2\nButton B: X+3, Y+3\nPrize: X=2, Y=2").unwrap()
+                [0];
+        machine.far_more().unwrap();
+        assert_eq!(
+            machine.calculate_cost_ex().unwrap(),
+            Some(10000000000002 / 3)
+        );
+    }
+
+    #[test]
+    fn test_13_01() {
+        assert_eq!(solve_part1(&parse_machines(INPUT).unwrap()).unwrap(), 480);
+    }
+}
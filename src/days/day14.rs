@@ -0,0 +1,366 @@
+use std::ops::Range;
+
+use crate::error::{AocError, AocResult};
+use crate::geometry::{IPoint, Point};
+use crate::grid::Grid;
+use crate::parse::{
+    field, from_fn, int, lines, literal, map, pair, parse_all, separated_pair, spaces, terminated,
+    uint, Failure, ParseError, Parser,
+};
+use crate::rng::Rng;
+use crate::{Answer, Solution};
+
+pub struct Day14;
+
+const WORLD_WIDTH: usize = 101;
+const WORLD_HEIGHT: usize = 103;
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pos: Point,
+    velocity: IPoint,
+    world_width: usize,
+    world_height: usize,
+}
+
+impl Robot {
+    fn update(&mut self) {
+        self.pos = self
+            .pos
+            .wrapping_add(self.velocity, self.world_width, self.world_height);
+    }
+
+    /// Position after `steps` seconds.
+    fn position_at(&self, steps: usize) -> Point {
+        self.pos.wrapping_add(
+            self.velocity * steps as isize,
+            self.world_width,
+            self.world_height,
+        )
+    }
+}
+
+/// Size of the world from an optional first line `size=11,7`, `WORLD_WIDTH` x `WORLD_HEIGHT` if
+/// missing.
+fn world_size() -> impl Parser<(usize, usize)> {
+    let line = terminated(
+        field("size", separated_pair(uint(), literal(","), uint())),
+        literal("\n"),
+    );
+    from_fn(move |input| match line.parse(input) {
+        Ok(((0, _) | (_, 0), _)) => Err(Failure::new("a world of at least 1x1", input)),
+        Ok(v) => Ok(v),
+        Err(failure) if failure.remaining == input.len() => {
+            Ok(((WORLD_WIDTH, WORLD_HEIGHT), input))
+        }
+        Err(failure) => Err(failure),
+    })
+}
+
+fn parse_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+    // p=0,4 v=3,-3
+    let robot = separated_pair(
+        field("p", separated_pair(uint(), literal(","), uint())),
+        spaces(),
+        field("v", separated_pair(int(), literal(","), int())),
+    );
+    let robots = map(
+        pair(world_size(), lines(robot)),
+        |((world_width, world_height), robots)| {
+            robots
+                .into_iter()
+                .map(|((x, y), (dx, dy))| Robot {
+                    pos: Point::new(x, y),
+                    velocity: IPoint::new(dx, dy),
+                    world_width,
+                    world_height,
+                })
+                .collect()
+        },
+    );
+    parse_all(robots, input)
+}
+
+fn check_tree(robots: &[Robot]) -> bool {
+    let has_robot = |robot: &Robot, row_offset: isize, col_offset: isize| -> bool {
+        let target = robot.pos.wrapping_add(
+            IPoint::new(row_offset, col_offset),
+            robot.world_width,
+            robot.world_height,
+        );
+        robots.iter().any(|x| x.pos == target)
+    };
+
+    for robot in robots.iter() {
+        if robot.pos.x < 2
+            || robot.pos.x + 2 > robot.world_width - 1
+            || robot.pos.y < 2
+            || robot.pos.y + 2 > robot.world_height - 1
+        {
+            continue;
+        }
+
+        // Check for tree shape.
+        //
+        // ---> X-axis
+        // |
+        // |
+        // V Y-axis
+        //
+        //    Current robot.
+        //    |
+        // ...#...
+        // ..###..
+        // .#####.
+        // ...#...
+        // ...#...
+        if
+        // 1st row.
+        !has_robot(robot, -1, 0) && !has_robot(robot, 1, 0) &&
+        // 2nd row
+        !has_robot(robot, -2, 1) && has_robot(robot, -1, 1) && has_robot(robot, 0, 1) && has_robot(robot, 1, 1) &&
+        // 3rd row
+        !has_robot(robot, -3, 2) && has_robot(robot, -2, 2) && has_robot(robot, -1, 2)&& has_robot(robot, 0, 2) && has_robot(robot, 1, 2) && has_robot(robot, 2, 2) && !has_robot(robot, 3, 2) &&
+        // 4th row
+        has_robot(robot, 0, 3) &&
+        // 5th row
+        has_robot(robot, 0, 4)
+        {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn solve_part1(robots: &[Robot]) -> AocResult<usize> {
+    let Some(first) = robots.first() else {
+        return Err(AocError::invalid("no robots"));
+    };
+    let world_width = first.world_width;
+    let world_height = first.world_height;
+    let mut robots = robots.to_vec();
+
+    for _ in 0..100 {
+        for robot in robots.iter_mut() {
+            robot.update();
+        }
+    }
+
+    let c0 = robots
+        .iter()
+        .filter(|r| r.pos.x < world_width / 2 && r.pos.y < world_height / 2)
+        .count();
+    let c1 = robots
+        .iter()
+        .filter(|r| r.pos.x > world_width / 2 && r.pos.y < world_height / 2)
+        .count();
+    let c2 = robots
+        .iter()
+        .filter(|r| r.pos.x < world_width / 2 && r.pos.y > world_height / 2)
+        .count();
+    let c3 = robots
+        .iter()
+        .filter(|r| r.pos.x > world_width / 2 && r.pos.y > world_height / 2)
+        .count();
+
+    Ok(c0 * c1 * c2 * c3)
+}
+
+/// Robots are back where they started after `width * height` seconds, the tree is not there if
+/// not seen by then.
+fn solve_part2(robots: &[Robot]) -> AocResult<usize> {
+    let Some(first) = robots.first() else {
+        return Err(AocError::invalid("no robots"));
+    };
+    let period = first.world_width * first.world_height;
+    let mut robots = robots.to_vec();
+
+    for sec in 0..period {
+        if check_tree(&robots) {
+            crate::debug!("tree found after {sec} seconds");
+            return Ok(sec);
+        }
+        for robot in robots.iter_mut() {
+            robot.update();
+        }
+    }
+
+    Err(AocError::invalid("no tree in any second"))
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Restroom Redoubt";
+
+    type Input = Vec<Robot>;
+
+    /// Puzzle inputs are in a world of `WORLD_WIDTH` x `WORLD_HEIGHT`, other sizes are given on
+    /// a first line, e.g. `size=11,7` for the example.
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_robots(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
+
+    /// Robots are `#`.
+    fn frames(
+        input: &Self::Input,
+        steps: Range<usize>,
+        frame: &mut dyn FnMut(usize, &Grid<char>) -> bool,
+    ) -> AocResult<()> {
+        let Some(first) = input.first() else {
+            return Ok(());
+        };
+        for step in steps {
+            let mut world = Grid::new(first.world_width, first.world_height, '.');
+            for robot in input.iter() {
+                world[robot.position_at(step)] = '#';
+            }
+            if !frame(step, &world) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// `size` robots moving around, and the robots of the tree that forms at a random second.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        // The shape `check_tree` looks for, from the top.
+        const TREE: [(usize, usize); 11] = [
+            (3, 0),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (1, 2),
+            (2, 2),
+            (3, 2),
+            (4, 2),
+            (5, 2),
+            (3, 3),
+            (3, 4),
+        ];
+        let second = rng.range(0..WORLD_WIDTH * WORLD_HEIGHT);
+        // Left of the box of the tree, 7 wide and 5 high.
+        let left = rng.range(0..WORLD_WIDTH - 7);
+        let top = rng.range(2..WORLD_HEIGHT - 7);
+        let in_box = |p: Point| (left..left + 7).contains(&p.x) && (top..top + 5).contains(&p.y);
+
+        let mut positions = TREE
+            .iter()
+            .map(|(x, y)| Point::new(left + x, top + y))
+            .collect::<Vec<_>>();
+        while positions.len() < TREE.len() + size {
+            let p = Point::new(rng.range(0..WORLD_WIDTH), rng.range(0..WORLD_HEIGHT));
+            if !in_box(p) {
+                positions.push(p);
+            }
+        }
+        rng.shuffle(&mut positions);
+
+        let mut out = String::new();
+        for p in positions {
+            let velocity = IPoint::new(
+                rng.range_i64(-100..101) as isize,
+                rng.range_i64(-100..101) as isize,
+            );
+            // Where the robot is at second 0.
+            let start = p.wrapping_add(-(velocity * second as isize), WORLD_WIDTH, WORLD_HEIGHT);
+            out.push_str(&format!(
+                "p={},{} v={},{}\n",
+                start.x, start.y, velocity.x, velocity.y
+            ));
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RawData;
+
+    const INPUT: RawData = r#"size=11,7
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3"#;
+
+    const WORLD_WIDTH: usize = 11;
+    const WORLD_HEIGHT: usize = 7;
+
+    #[test]
+    fn test_move() {
+        let mut robot = Robot {
+            pos: Point::new(2, 4),
+            velocity: IPoint::new(2, -3),
+            world_width: WORLD_WIDTH,
+            world_height: WORLD_HEIGHT,
+        };
+        robot.update();
+        assert_eq!(robot.pos, Point::new(4, 1));
+        robot.update();
+        assert_eq!(robot.pos, Point::new(6, 5));
+        robot.update();
+        assert_eq!(robot.pos, Point::new(8, 2));
+        robot.update();
+        assert_eq!(robot.pos, Point::new(10, 6));
+        robot.update();
+        assert_eq!(robot.pos, Point::new(1, 3));
+    }
+
+    #[test]
+    fn test_frames() {
+        let robots = parse_robots(INPUT).unwrap();
+        let mut frames = vec![];
+        Day14::frames(&robots, 100..101, &mut |step, world| {
+            frames.push((step, world.to_string()));
+            true
+        })
+        .unwrap();
+        #[rustfmt::skip]
+        let expected = [
+            "......#..#.",
+            "...........",
+            "#..........",
+            ".##........",
+            ".....#.....",
+            "...##......",
+            ".#....#....",
+        ];
+        assert_eq!(frames, [(100, expected.join("\n"))]);
+    }
+
+    #[test]
+    fn test_14_01() {
+        assert_eq!(solve_part1(&parse_robots(INPUT).unwrap()).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_world_size() {
+        let robots = parse_robots("p=0,4 v=3,-3").unwrap();
+        assert_eq!((robots[0].world_width, robots[0].world_height), (101, 103));
+        assert!(parse_robots("size=0,7\np=0,4 v=3,-3").is_err());
+        assert!(parse_robots("size=11\np=0,4 v=3,-3").is_err());
+    }
+
+    #[test]
+    fn test_no_tree() {
+        assert!(solve_part2(&parse_robots(INPUT).unwrap()).is_err());
+        assert!(solve_part1(&[]).is_err());
+    }
+}
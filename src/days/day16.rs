@@ -0,0 +1,202 @@
+use std::collections::HashSet;
+
+use crate::error::{AocError, AocResult};
+use crate::geometry::{Dir4, Point};
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::search::{dijkstra, Paths};
+use crate::{Answer, Solution};
+
+pub struct Day16;
+
+type World = Grid<char>;
+
+/// The reindeer is at a tile, facing some direction.
+type State = (Point, Dir4);
+
+const STEP_COST: usize = 1;
+
+const TURN_COST: usize = 1000;
+
+fn parse_world(input: &str) -> Result<World, ParseError> {
+    Grid::parse(input)
+}
+
+fn find_tile(world: &World, tile: char) -> AocResult<Point> {
+    world
+        .find(&tile)
+        .ok_or_else(|| AocError::invalid(format!("no {tile:?} in the maze")))
+}
+
+/// Best paths from the start facing east, with the end tile.
+fn search(world: &World) -> AocResult<(Paths<State>, Point)> {
+    let start = find_tile(world, 'S')?;
+    let end = find_tile(world, 'E')?;
+
+    let paths = dijkstra([(start, Dir4::Right)], |&(p, dir): &State| {
+        let forward = world
+            .offset(p, dir)
+            .filter(|next| world[*next] != '#')
+            .map(|next| ((next, dir), STEP_COST));
+        let turns = [dir.turn_left(), dir.turn_right()].map(|x| ((p, x), TURN_COST));
+        forward.into_iter().chain(turns)
+    });
+
+    Ok((paths, end))
+}
+
+/// End states reached with the lowest score.
+fn best_ends(paths: &Paths<State>, end: Point) -> AocResult<(usize, Vec<State>)> {
+    let best = Dir4::ALL
+        .iter()
+        .filter_map(|dir| paths.distance(&(end, *dir)))
+        .min()
+        .ok_or_else(|| AocError::invalid("end is not reachable"))?;
+    let ends = Dir4::ALL
+        .iter()
+        .map(|dir| (end, *dir))
+        .filter(|x| paths.distance(x) == Some(best))
+        .collect();
+    Ok((best, ends))
+}
+
+fn solve_part1(world: &World) -> AocResult<usize> {
+    let (paths, end) = search(world)?;
+    Ok(best_ends(&paths, end)?.0)
+}
+
+/// Count tiles on any of the best paths.
+fn solve_part2(world: &World) -> AocResult<usize> {
+    let (paths, end) = search(world)?;
+    let (_, ends) = best_ends(&paths, end)?;
+    let tiles = paths
+        .on_best_paths(ends)
+        .into_iter()
+        .map(|(p, _)| p)
+        .collect::<HashSet<_>>();
+    Ok(tiles.len())
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Reindeer Maze";
+
+    type Input = World;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {
+        Ok(parse_world(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(input)?.into())
+    }
+
+    /// Maze of `size`×`size`, rounded up to odd, with a few loops. The start is in the bottom left
+    /// corner and the end in the top right corner.
+    fn generate(rng: &mut Rng, size: usize) -> AocResult<String> {
+        let size = size.max(5) | 1;
+        let mut world = Grid::new(size, size, '#');
+
+        // Randomized depth-first search over cells at odd positions.
+        let start = Point::new(1, size - 2);
+        world[start] = '.';
+        let mut stack = vec![start];
+        while let Some(&p) = stack.last() {
+            let mut dirs = Dir4::ALL;
+            rng.shuffle(&mut dirs);
+            let next = dirs.into_iter().find_map(|dir| {
+                let wall = world.offset(p, dir)?;
+                let next = world.offset(wall, dir)?;
+                let inside = next.x % (size - 1) != 0 && next.y % (size - 1) != 0;
+                (inside && world[next] == '#').then_some((wall, next))
+            });
+            match next {
+                Some((wall, next)) => {
+                    world[wall] = '.';
+                    world[next] = '.';
+                    stack.push(next);
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+
+        // Open some walls between two corridors, so that there are several best paths.
+        for y in 1..size - 1 {
+            for x in 1..size - 1 {
+                let p = Point::new(x, y);
+                let between = |a: Point, b: Point| world[a] == '.' && world[b] == '.';
+                let corridor = between(Point::new(x - 1, y), Point::new(x + 1, y))
+                    || between(Point::new(x, y - 1), Point::new(x, y + 1));
+                if world[p] == '#' && corridor && rng.chance(0.05) {
+                    world[p] = '.';
+                }
+            }
+        }
+
+        world[start] = 'S';
+        world[Point::new(size - 2, 1)] = 'E';
+        Ok(world.to_string() + "\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::RawData;
+
+    const EXAMPLE_1: RawData = r#"###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############"#;
+
+    const EXAMPLE_2: RawData = r#"#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################"#;
+
+    #[test]
+    fn test_16_01() {
+        assert_eq!(solve_part1(&parse_world(EXAMPLE_1).unwrap()).unwrap(), 7036);
+        assert_eq!(
+            solve_part1(&parse_world(EXAMPLE_2).unwrap()).unwrap(),
+            11048
+        );
+    }
+
+    #[test]
+    fn test_16_02() {
+        assert_eq!(solve_part2(&parse_world(EXAMPLE_1).unwrap()).unwrap(), 45);
+        assert_eq!(solve_part2(&parse_world(EXAMPLE_2).unwrap()).unwrap(), 64);
+    }
+}
@@ -0,0 +1,96 @@
+//! Solutions of all days, and the registry of them.
+
+use crate::solution::Puzzle;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+
+/// All registered days, in order.
+pub static DAYS: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+];
+
+/// Find the registered puzzle of `day`.
+pub fn find(day: u8) -> Option<&'static dyn Puzzle> {
+    DAYS.iter().find(|x| x.day() == day).copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rng::Rng;
+    use crate::runner::{self, Outcome};
+    use crate::Part;
+
+    #[test]
+    fn test_registry_order() {
+        let days = DAYS.iter().map(|x| x.day()).collect::<Vec<_>>();
+        let mut sorted = days.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(days, sorted);
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(4).map(|x| x.day()), Some(4));
+        assert!(find(0).is_none());
+    }
+
+    /// Parts known to fail on generated inputs.
+    const KNOWN_FAILURES: [(u8, Part); 1] = [(15, Part::Two)];
+
+    /// Generated inputs are solved, apart from [`KNOWN_FAILURES`], which must not panic either.
+    #[test]
+    fn test_generate() {
+        for puzzle in DAYS {
+            for seed in 0..3 {
+                let input = puzzle.generate(&mut Rng::new(seed), 4).unwrap();
+                let report = runner::run_day(*puzzle, &input, &Part::ALL);
+                for part in report.parts {
+                    let known = KNOWN_FAILURES.contains(&(puzzle.day(), part.part));
+                    assert!(
+                        match part.outcome {
+                            Outcome::Solved(_) => true,
+                            Outcome::Panicked(_) => false,
+                            _ => known,
+                        },
+                        "day {} {} with seed {seed}: {}\n{input}",
+                        puzzle.day(),
+                        part.part,
+                        part.outcome
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Differential testing, run two implementations of the same question on generated inputs.
+//!
+//! Days keep a brute force next to a fast solver, both are run on inputs generated from
+//! consecutive seeds until they disagree:
+//!
+//! ```text
+//! differential::check(&Config::default(), |rng| rng.range(0..100), slow, fast);
+//! ```
+//!
+//! A panic of either side is a disagreement as well. A reported case is replayed alone with
+//! `Config { seed, cases: 1 }`.
+
+use std::fmt;
+
+use crate::rng::Rng;
+use crate::runner;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Seed of the first case, case `n` uses `seed + n`.
+    pub seed: u64,
+
+    pub cases: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seed: 2024,
+            cases: 200,
+        }
+    }
+}
+
+/// First generated input the implementations disagree on.
+#[derive(Debug, Clone)]
+pub struct Mismatch<I> {
+    pub case: usize,
+    pub seed: u64,
+    pub input: I,
+
+    /// Output of each side, or how it panicked.
+    pub left: String,
+    pub right: String,
+}
+
+impl<I: fmt::Debug> fmt::Display for Mismatch<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "case {} with seed {} differs", self.case, self.seed)?;
+        writeln!(f, "input: {:?}", self.input)?;
+        writeln!(f, " left: {}", self.left)?;
+        write!(f, "right: {}", self.right)
+    }
+}
+
+/// Run `left` and `right` on `config.cases` inputs of `generate`.
+pub fn compare<I, O>(
+    config: &Config,
+    mut generate: impl FnMut(&mut Rng) -> I,
+    left: impl Fn(&I) -> O,
+    right: impl Fn(&I) -> O,
+) -> Result<(), Mismatch<I>>
+where
+    O: PartialEq + fmt::Debug,
+{
+    for case in 0..config.cases {
+        let seed = config.seed.wrapping_add(case as u64);
+        let input = generate(&mut Rng::new(seed));
+        let left = runner::catch(|| left(&input));
+        let right = runner::catch(|| right(&input));
+        if matches!((&left, &right), (Ok(l), Ok(r)) if l == r) {
+            continue;
+        }
+
+        let show = |x: Result<O, runner::Outcome>| match x {
+            Ok(v) => format!("{v:?}"),
+            Err(outcome) => outcome.to_string(),
+        };
+        return Err(Mismatch {
+            case,
+            seed,
+            input,
+            left: show(left),
+            right: show(right),
+        });
+    }
+    Ok(())
+}
+
+/// Like [`compare`], panics with the mismatch, for tests.
+pub fn check<I, O>(
+    config: &Config,
+    generate: impl FnMut(&mut Rng) -> I,
+    left: impl Fn(&I) -> O,
+    right: impl Fn(&I) -> O,
+) where
+    I: fmt::Debug,
+    O: PartialEq + fmt::Debug,
+{
+    if let Err(mismatch) = compare(config, generate, left, right) {
+        panic!("{mismatch}");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        let config = Config::default();
+        let square = |x: &u64| x * x;
+        assert!(compare(&config, |rng| rng.below(1000), square, |x| x.pow(2)).is_ok());
+
+        let mismatch = compare(
+            &config,
+            |rng| rng.below(1000),
+            square,
+            |x| {
+                if *x > 500 {
+                    x * x + 1
+                } else {
+                    x * x
+                }
+            },
+        )
+        .unwrap_err();
+        assert!(mismatch.input > 500);
+        assert_ne!(mismatch.left, mismatch.right);
+
+        // The reported seed replays the input.
+        let replay = Config {
+            seed: mismatch.seed,
+            cases: 1,
+        };
+        let again = compare(&replay, |rng| rng.below(1000), square, |x| x * x + 1).unwrap_err();
+        assert_eq!((again.case, again.input), (0, mismatch.input));
+
+        let panicked = compare(
+            &config,
+            |rng| rng.below(10),
+            square,
+            |_| -> u64 { panic!("boom") },
+        )
+        .unwrap_err();
+        assert!(panicked.right.contains("boom"));
+    }
+}
@@ -0,0 +1,73 @@
+//! Errors of loading, parsing and solving puzzles.
+
+use std::fmt;
+
+use crate::input::InputError;
+use crate::parse::ParseError;
+
+pub type AocResult<T> = Result<T, AocError>;
+
+#[derive(Debug)]
+pub enum AocError {
+    /// Failed to load the input.
+    Io(InputError),
+
+    /// Malformed input, with line, column and the line itself.
+    Parse(ParseError),
+
+    /// Input is well formed but the puzzle can not go on, e.g. no start point.
+    InvalidState(String),
+
+    /// Solver math overflowed, only reported with the `checked-math` feature.
+    Overflow(String),
+
+    /// Heap peak went over the memory budget.
+    Budget(String),
+
+    /// The part is not solved yet.
+    Unimplemented,
+}
+
+impl AocError {
+    pub fn invalid(msg: impl Into<String>) -> Self {
+        AocError::InvalidState(msg.into())
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "{e}"),
+            AocError::Parse(e) => write!(f, "parse error at {e}"),
+            AocError::InvalidState(msg) => write!(f, "invalid state: {msg}"),
+            AocError::Overflow(msg) => write!(f, "overflow: {msg}"),
+            AocError::Budget(msg) => write!(f, "memory budget exceeded: {msg}"),
+            AocError::Unimplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            AocError::Parse(e) => Some(e),
+            AocError::InvalidState(_)
+            | AocError::Overflow(_)
+            | AocError::Budget(_)
+            | AocError::Unimplemented => None,
+        }
+    }
+}
+
+impl From<InputError> for AocError {
+    fn from(e: InputError) -> Self {
+        AocError::Io(e)
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(e: ParseError) -> Self {
+        AocError::Parse(e)
+    }
+}
@@ -0,0 +1,52 @@
+// Header of fixture files, see `src/fixtures.rs` for the format.
+//
+// Included by both `src/fixtures.rs` and `build.rs`, so it only uses std and imports nothing.
+
+/// Line separates the header and the input.
+pub const SEPARATOR: &str = "---";
+
+/// Expected answers and ignored parts of a fixture header, by part number.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Header {
+    pub expected: std::collections::BTreeMap<u8, String>,
+
+    /// Parts not checked, with reasons.
+    pub ignored: std::collections::BTreeMap<u8, String>,
+}
+
+/// Parse the header of fixture `content`, return it with the input after the separator.
+pub fn parse_header(content: &str) -> Result<(Header, &str), String> {
+    let mut header = Header::default();
+    let mut offset = 0;
+    for (idx, line) in content.split_inclusive('\n').enumerate() {
+        offset += line.len();
+        let line = line.trim();
+        if line == SEPARATOR {
+            return Ok((header, &content[offset..]));
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let invalid = || format!("invalid fixture header at line {}: {line:?}", idx + 1);
+        let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+        let (key, value) = (key.trim(), value.trim().to_owned());
+        let (part, ignore) = match key.split_once('.') {
+            Some((part, "ignore")) => (part, true),
+            Some(_) => return Err(invalid()),
+            None => (key, false),
+        };
+        let part = part
+            .strip_prefix("part")
+            .and_then(|x| x.parse::<u8>().ok())
+            .filter(|x| matches!(x, 1 | 2))
+            .ok_or_else(invalid)?;
+        if ignore {
+            header.ignored.insert(part, value);
+        } else {
+            header.expected.insert(part, value);
+        }
+    }
+
+    Err(format!("missing {SEPARATOR} between header and input"))
+}
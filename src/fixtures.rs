@@ -0,0 +1,126 @@
+//! Example inputs with expected answers.
+//!
+//! Fixtures of day `N` live in `fixtures/NN/`, one example per file. A fixture file has a header
+//! with expected answers, a `---` line, then the example input:
+//!
+//! ```text
+//! # Comments start with `#`.
+//! part1 = 18
+//! part2 = 9
+//! part2.ignore = reason to skip this part
+//! ---
+//! MMMSXXMASM
+//! ...
+//! ```
+//!
+//! A test is generated for every part with an expected answer in every fixture, see `build.rs`.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::runner::{self, Outcome};
+use crate::{days, Part};
+
+/// Directory of fixtures.
+pub const FIXTURES_DIR: &str = "fixtures";
+
+include!("fixture_header.rs");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub expected: BTreeMap<Part, String>,
+
+    /// Parts not checked, with reasons.
+    pub ignored: BTreeMap<Part, String>,
+
+    pub input: String,
+}
+
+impl Fixture {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let (header, input) = parse_header(content)?;
+        let by_part = |map: BTreeMap<u8, String>| {
+            map.into_iter()
+                .map(|(part, value)| {
+                    let part =
+                        Part::from_number(part).ok_or_else(|| format!("invalid part {part}"))?;
+                    Ok((part, value))
+                })
+                .collect::<Result<BTreeMap<_, _>, String>>()
+        };
+
+        Ok(Self {
+            expected: by_part(header.expected)?,
+            ignored: by_part(header.ignored)?,
+            input: input.trim_end_matches(['\n', '\r']).to_owned(),
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        Self::parse(&content).map_err(|e| format!("{}: {e}", path.display()))
+    }
+}
+
+/// All fixture files of `day` in `dir`, sorted by name.
+pub fn fixture_paths(dir: &Path, day: u8) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir.join(format!("{day:02}"))) else {
+        return vec![];
+    };
+    let mut paths = entries
+        .filter_map(|x| x.ok())
+        .map(|x| x.path())
+        .filter(|x| x.extension().is_some_and(|ext| ext == "txt"))
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+/// Run `part` of `day` with the fixture in `path` and compare with the expected answer.
+pub fn check(day: u8, part: Part, path: &Path) -> Result<(), String> {
+    let fixture = Fixture::load(path)?;
+    let expected = fixture
+        .expected
+        .get(&part)
+        .ok_or_else(|| format!("{}: no expected answer of {part}", path.display()))?;
+    let puzzle = days::find(day).ok_or_else(|| format!("day {day} is not registered"))?;
+
+    let report = runner::run_day(puzzle, &fixture.input, &[part]);
+    match &report.parts[0].outcome {
+        Outcome::Solved(answer) if &answer.to_string() == expected => Ok(()),
+        Outcome::Solved(answer) => Err(format!(
+            "{}: {part} expected {expected}, got {answer}",
+            path.display()
+        )),
+        outcome => Err(format!("{}: {part} {outcome}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_fixture() {
+        let fixture = Fixture::parse(
+            "# comment\npart1 = 18\npart2 = 9\npart2.ignore = wrong\n---\nXMAS\nSAMX\n",
+        )
+        .unwrap();
+        assert_eq!(fixture.expected[&Part::One], "18");
+        assert_eq!(fixture.expected[&Part::Two], "9");
+        assert_eq!(fixture.ignored[&Part::Two], "wrong");
+        assert_eq!(fixture.input, "XMAS\nSAMX");
+    }
+
+    #[test]
+    fn test_parse_invalid_fixture() {
+        assert!(Fixture::parse("part1 = 1\nXMAS\n").is_err());
+        assert!(Fixture::parse("part3 = 1\n---\nXMAS\n").is_err());
+        assert!(Fixture::parse("part1.skip = 1\n---\nXMAS\n").is_err());
+    }
+
+    mod generated {
+        include!(concat!(env!("OUT_DIR"), "/fixture_tests.rs"));
+    }
+}
@@ -0,0 +1,300 @@
+//! Points and directions on grids.
+//!
+//! Axis directions, the same as text on screen:
+//!
+//! ```text
+//! ------> x-axis (column)
+//! |
+//! |
+//! v
+//! y-axis (row)
+//! ```
+
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A point on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn to_signed(self) -> IPoint {
+        IPoint::new(self.x as isize, self.y as isize)
+    }
+
+    /// Point moved by `offset`, `None` if any axis goes negative.
+    pub fn checked_add(self, offset: impl Into<IPoint>) -> Option<Self> {
+        let offset = offset.into();
+        Some(Self::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+
+    /// Point moved by `offset`, wrapping around the edges of a `width` x `height` world.
+    pub fn wrapping_add(self, offset: impl Into<IPoint>, width: usize, height: usize) -> Self {
+        let p = self.to_signed() + offset.into();
+        Self::new(
+            p.x.rem_euclid(width as isize) as usize,
+            p.y.rem_euclid(height as isize) as usize,
+        )
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A signed point, also used as an offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IPoint {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl IPoint {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// `None` if any axis is negative.
+    pub fn to_unsigned(self) -> Option<Point> {
+        Some(Point::new(
+            usize::try_from(self.x).ok()?,
+            usize::try_from(self.y).ok()?,
+        ))
+    }
+
+    /// Rotate 90 degrees clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotate 90 degrees counterclockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl fmt::Display for IPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for IPoint {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for IPoint {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Mul<isize> for IPoint {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for IPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Four directions, clockwise from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    /// Parse arrows `^`, `>`, `v` and `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Dir4::Up),
+            '>' => Some(Dir4::Right),
+            'v' => Some(Dir4::Down),
+            '<' => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+
+    /// The arrow of [`Dir4::from_arrow`].
+    pub fn arrow(self) -> char {
+        match self {
+            Dir4::Up => '^',
+            Dir4::Right => '>',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+        }
+    }
+
+    pub fn offset(self) -> IPoint {
+        match self {
+            Dir4::Up => IPoint::new(0, -1),
+            Dir4::Right => IPoint::new(1, 0),
+            Dir4::Down => IPoint::new(0, 1),
+            Dir4::Left => IPoint::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Dir4::Left | Dir4::Right)
+    }
+}
+
+impl From<Dir4> for IPoint {
+    fn from(dir: Dir4) -> Self {
+        dir.offset()
+    }
+}
+
+/// Eight directions including diagonal ones, clockwise from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    pub fn offset(self) -> IPoint {
+        match self {
+            Dir8::Up => IPoint::new(0, -1),
+            Dir8::UpRight => IPoint::new(1, -1),
+            Dir8::Right => IPoint::new(1, 0),
+            Dir8::DownRight => IPoint::new(1, 1),
+            Dir8::Down => IPoint::new(0, 1),
+            Dir8::DownLeft => IPoint::new(-1, 1),
+            Dir8::Left => IPoint::new(-1, 0),
+            Dir8::UpLeft => IPoint::new(-1, -1),
+        }
+    }
+
+    /// Turn 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turn 45 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir8> for IPoint {
+    fn from(dir: Dir8) -> Self {
+        dir.offset()
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.reverse(), Dir4::Right);
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+        assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+        assert_eq!(Dir8::DownRight.reverse(), Dir8::UpLeft);
+        for dir in Dir4::ALL {
+            assert_eq!(dir.offset().rotate_right(), dir.turn_right().offset());
+            assert_eq!(dir.offset().rotate_left(), dir.turn_left().offset());
+            assert_eq!(Dir8::from(dir).offset(), dir.offset());
+        }
+    }
+
+    #[test]
+    fn test_steps() {
+        let p = Point::new(0, 2);
+        assert_eq!(p.checked_add(Dir4::Left), None);
+        assert_eq!(p.checked_add(Dir8::UpRight), Some(Point::new(1, 1)));
+        assert_eq!(p.wrapping_add(IPoint::new(-1, 5), 11, 7), Point::new(10, 0));
+        assert_eq!(
+            p.wrapping_add(IPoint::new(-23, -16), 11, 7),
+            Point::new(10, 0)
+        );
+        assert_eq!(p.manhattan(Point::new(3, 0)), 5);
+        assert_eq!(IPoint::new(-1, 2).to_unsigned(), None);
+        assert_eq!(
+            IPoint::new(1, 2) * 2 - IPoint::new(3, 3),
+            -IPoint::new(1, -1)
+        );
+    }
+}
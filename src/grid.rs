@@ -0,0 +1,346 @@
+//! Rectangular grids.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{Dir4, Dir8, IPoint, Point};
+use crate::parse::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+
+    /// Line `line` (0-based) has `width` cells while the first line has `expected`.
+    NotRectangular {
+        line: usize,
+        width: usize,
+        expected: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "empty grid"),
+            GridError::NotRectangular {
+                line,
+                width,
+                expected,
+            } => write!(
+                f,
+                "grid is not rectangular: line {} has {width} cells, expected {expected}",
+                line + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// A rectangular grid, cells are stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Parse lines of `input` as rows.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, |c| c)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parse lines of `input` as rows, convert each char with `f`.
+    pub fn parse_with(input: &str, f: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        let mut f = f;
+        let lines = input.trim_end_matches(['\n', '\r']).lines();
+        Self::from_rows(lines.map(|line| line.chars().map(&mut f).collect())).map_err(|e| {
+            let offset = match e {
+                GridError::Empty => 0,
+                GridError::NotRectangular {
+                    line,
+                    width,
+                    expected,
+                } => {
+                    let start = input
+                        .split_inclusive('\n')
+                        .take(line)
+                        .map(str::len)
+                        .sum::<usize>();
+                    let row = &input[start..];
+                    start
+                        + row
+                            .char_indices()
+                            .nth(width.min(expected))
+                            .map_or(row.len(), |(idx, _)| idx)
+                }
+            };
+            ParseError::at(input, offset, e.to_string())
+        })
+    }
+
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for row in rows {
+            let expected = *width.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(GridError::NotRectangular {
+                    line: height,
+                    width: row.len(),
+                    expected,
+                });
+            }
+            cells.extend(row);
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(GridError::Empty),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        if self.contains(p) {
+            Some(&self.cells[p.y * self.width + p.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.y * self.width + p.x])
+        } else {
+            None
+        }
+    }
+
+    /// Point moved by `offset`, `None` if out of grid.
+    ///
+    /// `offset` can be an [`IPoint`] or a direction.
+    pub fn offset(&self, p: Point, offset: impl Into<IPoint>) -> Option<Point> {
+        p.checked_add(offset).filter(|p| self.contains(*p))
+    }
+
+    /// Up, right, down and left neighbours inside the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Dir4::ALL.into_iter().filter_map(move |d| self.offset(p, d))
+    }
+
+    /// All 8 neighbours inside the grid, including diagonal ones.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Dir8::ALL.into_iter().filter_map(move |d| self.offset(p, d))
+    }
+
+    /// Point of cell `idx`, a grid of width 0 has no cells to ask for.
+    fn point_of(&self, idx: usize) -> Point {
+        Point::new(idx % self.width, idx / self.width)
+    }
+
+    /// All points, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|idx| self.point_of(idx))
+    }
+
+    /// All cells with their points, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, v)| (self.point_of(idx), v))
+    }
+
+    /// First point where `f` returns true, row by row.
+    pub fn position(&self, f: impl Fn(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(f).map(|idx| self.point_of(idx))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// All rows, a grid of width 0 has `height` empty rows.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        // A grid of width 0 has no cells to step over.
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Grid of `width` x `height` cells of `value`, either may be 0 for a grid without cells.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// First point of `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Point> {
+        self.position(|x| x == value)
+    }
+
+    /// All points of `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, x)| *x == value)
+            .map(|(p, _)| p)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        self.get(p)
+            .unwrap_or_else(|| panic!("point {p} out of grid {}x{}", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("point {p} out of grid {width}x{height}"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "abc\ndef";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 0)], 'c');
+        assert_eq!(grid[Point::new(0, 1)], 'd');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+
+        let err = Grid::parse("ab\nc").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(
+            err.message,
+            "grid is not rectangular: line 2 has 1 cells, expected 2"
+        );
+        assert!(Grid::parse("").is_err());
+        assert_eq!(
+            Grid::from_rows(vec![vec![1], vec![]]),
+            Err(GridError::NotRectangular {
+                line: 1,
+                width: 0,
+                expected: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::parse(INPUT).unwrap();
+        let n4 = grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>();
+        assert_eq!(n4, [Point::new(1, 0), Point::new(0, 1)]);
+        let n8 = grid.neighbours8(Point::new(1, 1)).collect::<Vec<_>>();
+        assert_eq!(n8.len(), 5);
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_find_and_iterate() {
+        let grid = Grid::parse("a.a\n.a.").unwrap();
+        assert_eq!(grid.find(&'.'), Some(Point::new(1, 0)));
+        assert_eq!(grid.find(&'x'), None);
+        assert_eq!(
+            grid.find_all(&'a').collect::<Vec<_>>(),
+            [Point::new(0, 0), Point::new(2, 0), Point::new(1, 1)]
+        );
+        assert_eq!(grid.row(1), ['.', 'a', '.']);
+        assert_eq!(grid.column(2).collect::<String>(), "a.");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.rows().count(), 2);
+
+        let mapped = grid.map(|x| *x == 'a');
+        assert!(mapped[Point::new(1, 1)]);
+        assert!(!mapped[Point::new(1, 0)]);
+    }
+
+    #[test]
+    fn test_no_cells() {
+        let grid = Grid::new(0, 3, 'x');
+        assert_eq!((grid.width(), grid.height()), (0, 3));
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[]; 3]);
+        assert_eq!(grid.points().count(), 0);
+        assert_eq!(grid.iter().count(), 0);
+        assert_eq!(grid.find(&'x'), None);
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.get(Point::new(0, 0)), None);
+        assert_eq!(grid.to_string(), "\n\n");
+
+        let grid = Grid::new(3, 0, 'x');
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.points().count(), 0);
+        assert_eq!(grid.to_string(), "");
+    }
+}
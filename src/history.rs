@@ -0,0 +1,375 @@
+//! History of benchmark runs, to find out when a change makes a day slower.
+//!
+//! Every benchmark run appends its medians to `bench_history.txt` in the data directory, one stage
+//! per line:
+//!
+//! ```text
+//! # run label revision day stage median_ns input_hash
+//! 1 - 173dd3f 06 part2 114410 8f3b6c0d52a1e9f4
+//! 2 baseline 1a24920 06 part2 98200 8f3b6c0d52a1e9f4
+//! ```
+//!
+//! `label` and `revision` are `-` if not given or not known. Medians are only compared between
+//! the same inputs, told apart by the hash.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::bench::{DayBench, Stage};
+use crate::line_file;
+use crate::runner;
+use crate::Part;
+
+/// Name of the history file in the data directory.
+pub const HISTORY_FILE: &str = "bench_history.txt";
+
+const HEADER: &str = "# run label revision day stage median_ns input_hash";
+
+/// Path of the history file in use.
+pub fn history_path() -> PathBuf {
+    crate::input::data_dir().join(HISTORY_FILE)
+}
+
+/// FNV-1a hash of an input.
+pub fn hash_input(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, x| {
+        (hash ^ x as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Short hash of the commit checked out in the repository at `root`, read from `.git/HEAD`.
+pub fn git_revision(root: &Path) -> Option<String> {
+    let git = root.join(".git");
+    let head = std::fs::read_to_string(git.join("HEAD")).ok()?;
+    let head = head.trim();
+    let hash = match head.strip_prefix("ref: ") {
+        Some(name) => match std::fs::read_to_string(git.join(name)) {
+            Ok(hash) => hash.trim().to_owned(),
+            // Branches not touched since `git gc` only live in `packed-refs`.
+            Err(_) => std::fs::read_to_string(git.join("packed-refs"))
+                .ok()?
+                .lines()
+                .find_map(|line| {
+                    let (hash, ref_name) = line.split_once(' ')?;
+                    (ref_name == name).then(|| hash.to_owned())
+                })?,
+        },
+        None => head.to_owned(),
+    };
+    (hash.len() >= 7 && hash.chars().all(|x| x.is_ascii_hexdigit())).then(|| hash[..7].to_owned())
+}
+
+/// Median of one stage in one run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Number of the run, increasing.
+    pub run: u64,
+    pub label: Option<String>,
+    pub revision: Option<String>,
+    pub day: u8,
+    pub stage: Stage,
+    pub median: Duration,
+    pub input_hash: u64,
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {:02} {} {} {:016x}",
+            self.run,
+            self.label.as_deref().unwrap_or("-"),
+            self.revision.as_deref().unwrap_or("-"),
+            self.day,
+            self.stage.name(),
+            self.median.as_nanos(),
+            self.input_hash
+        )
+    }
+}
+
+/// Records of all runs, in the order run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    records: Vec<Record>,
+}
+
+impl History {
+    /// Parse history file content, a [`line_file`].
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut records = vec![];
+        for (number, line) in line_file::entries(content) {
+            let invalid = || format!("invalid record at line {number}: {line:?}");
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [run, label, revision, day, stage, median, input_hash] = fields[..] else {
+                return Err(invalid());
+            };
+            let optional = |x: &str| (x != "-").then(|| x.to_owned());
+            records.push(Record {
+                run: run.parse().map_err(|_| invalid())?,
+                label: optional(label),
+                revision: optional(revision),
+                day: day.parse().map_err(|_| invalid())?,
+                stage: parse_stage(stage).ok_or_else(invalid)?,
+                median: Duration::from_nanos(median.parse().map_err(|_| invalid())?),
+                input_hash: u64::from_str_radix(input_hash, 16).map_err(|_| invalid())?,
+            });
+        }
+
+        Ok(Self { records })
+    }
+
+    /// Load the history from `path`, a missing file has no run.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        line_file::load(path, Self::parse)
+    }
+
+    /// Append `records` to the file at `path`, created with a header if missing.
+    pub fn append(path: &Path, records: &[Record]) -> io::Result<()> {
+        let is_new = !path.exists();
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        let mut out = String::new();
+        if is_new {
+            out.push_str(HEADER);
+            out.push('\n');
+        }
+        for record in records {
+            out.push_str(&format!("{record}\n"));
+        }
+        file.write_all(out.as_bytes())
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Number of the next run.
+    pub fn next_run(&self) -> u64 {
+        self.records.iter().map(|x| x.run + 1).max().unwrap_or(1)
+    }
+
+    /// Latest record of the same stage and input as `current`, in the run labeled `label` if
+    /// given.
+    pub fn baseline(&self, current: &Record, label: Option<&str>) -> Option<&Record> {
+        let run = match label {
+            Some(label) => Some(
+                self.records
+                    .iter()
+                    .rev()
+                    .find(|x| x.label.as_deref() == Some(label))?
+                    .run,
+            ),
+            None => None,
+        };
+        self.records.iter().rev().find(|x| {
+            run.is_none_or(|run| x.run == run)
+                && x.run != current.run
+                && (x.day, x.stage, x.input_hash)
+                    == (current.day, current.stage, current.input_hash)
+        })
+    }
+}
+
+fn parse_stage(name: &str) -> Option<Stage> {
+    match name {
+        "parse" => Some(Stage::Parse),
+        v => v
+            .strip_prefix("part")?
+            .parse::<u8>()
+            .ok()
+            .and_then(Part::from_number)
+            .map(Stage::Solve),
+    }
+}
+
+/// Records of measured stages of `bench`, whose input hashes to `input_hash`.
+pub fn records(
+    run: u64,
+    label: Option<&str>,
+    revision: Option<&str>,
+    bench: &DayBench,
+    input_hash: u64,
+) -> Vec<Record> {
+    bench
+        .stages
+        .iter()
+        .filter_map(|stage| {
+            Some(Record {
+                run,
+                label: label.map(String::from),
+                revision: revision.map(String::from),
+                day: bench.day,
+                stage: stage.stage,
+                median: stage.result.as_ref().ok()?.median,
+                input_hash,
+            })
+        })
+        .collect()
+}
+
+/// Median of a stage against its baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline: Option<Record>,
+    pub median: Duration,
+
+    /// Whether the median is slower than the baseline by more than the threshold.
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// Relative change of the median, e.g. `0.25` for 25% slower.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline.as_ref()?.median.as_secs_f64();
+        Some(self.median.as_secs_f64() / baseline.max(f64::EPSILON) - 1.0)
+    }
+}
+
+/// Compare `current` records against their baselines in `history`, a stage regressed if its
+/// median is more than `threshold` (e.g. `0.1` for 10%) slower.
+pub fn compare(
+    history: &History,
+    current: &[Record],
+    label: Option<&str>,
+    threshold: f64,
+) -> Vec<Comparison> {
+    current
+        .iter()
+        .map(|record| {
+            let baseline = history.baseline(record, label).cloned();
+            let mut comparison = Comparison {
+                day: record.day,
+                stage: record.stage,
+                baseline,
+                median: record.median,
+                regressed: false,
+            };
+            comparison.regressed = comparison.change().is_some_and(|x| x > threshold);
+            comparison
+        })
+        .collect()
+}
+
+/// Format comparisons as a table.
+pub fn format_comparisons(comparisons: &[Comparison]) -> String {
+    let header = ["Day", "Stage", "Baseline", "Median", "Change", "Status"];
+    let rows = comparisons
+        .iter()
+        .map(|x| {
+            let (baseline, change, status) = match (&x.baseline, x.change()) {
+                (Some(baseline), Some(change)) => (
+                    format!(
+                        "{:.2?} ({})",
+                        baseline.median,
+                        baseline.revision.as_deref().unwrap_or("-")
+                    ),
+                    format!("{:+.1}%", change * 100.0),
+                    if x.regressed { "regressed" } else { "ok" },
+                ),
+                _ => (String::new(), String::new(), "no baseline"),
+            };
+            [
+                format!("{:02}", x.day),
+                x.stage.name(),
+                baseline,
+                format!("{:.2?}", x.median),
+                change,
+                status.to_owned(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    runner::table(&header, &rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(run: u64, label: Option<&str>, stage: Stage, median_us: u64) -> Record {
+        Record {
+            run,
+            label: label.map(String::from),
+            revision: Some(String::from("173dd3f")),
+            day: 6,
+            stage,
+            median: Duration::from_micros(median_us),
+            input_hash: hash_input("..#\n.^."),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let records = [
+            record(1, None, Stage::Parse, 10),
+            record(2, Some("fast"), Stage::Solve(Part::Two), 120),
+        ];
+        let content = format!("{HEADER}\n{}\n\n{}\n", records[0], records[1]);
+        let history = History::parse(&content).unwrap();
+        assert_eq!(history.records(), records);
+        assert_eq!(history.next_run(), 3);
+        assert!(History::parse("1 - - 06 part3 10 ff").is_err());
+        assert!(History::parse("1 - - 06 part1 10").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let part2 = Stage::Solve(Part::Two);
+        let history = History {
+            records: vec![
+                record(1, Some("before"), part2, 100),
+                record(2, None, part2, 200),
+                record(3, None, Stage::Parse, 10),
+            ],
+        };
+        let current = [record(4, None, part2, 150), record(4, None, part2, 250)];
+
+        // Against the previous run, 150 is faster than 200 and 250 is 25% slower.
+        let comparisons = compare(&history, &current, None, 0.1);
+        assert_eq!(comparisons[0].baseline.as_ref().unwrap().run, 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert!(!compare(&history, &current[1..], None, 0.3)[0].regressed);
+
+        let comparisons = compare(&history, &current, Some("before"), 0.1);
+        assert_eq!(comparisons[0].baseline.as_ref().unwrap().run, 1);
+        assert!(comparisons[0].regressed);
+
+        // Other inputs are not comparable.
+        let other = Record {
+            input_hash: hash_input("other"),
+            ..current[0].clone()
+        };
+        let comparisons = compare(&history, &[other], None, 0.1);
+        assert_eq!(
+            (comparisons[0].baseline.as_ref(), comparisons[0].regressed),
+            (None, false)
+        );
+        assert!(compare(&history, &current, Some("missing"), 0.1)[0]
+            .baseline
+            .is_none());
+    }
+
+    #[test]
+    fn test_git_revision() {
+        let root = std::env::temp_dir().join(format!("aoc2024-history-{}", std::process::id()));
+        let git = root.join(".git");
+        std::fs::create_dir_all(git.join("refs/heads")).unwrap();
+        std::fs::write(git.join("HEAD"), "ref: refs/heads/main\n").unwrap();
+        assert_eq!(git_revision(&root), None);
+
+        let hash = "1a24920c0ffee0000000000000000000000000aa";
+        std::fs::write(git.join("packed-refs"), format!("{hash} refs/heads/main\n")).unwrap();
+        assert_eq!(git_revision(&root).as_deref(), Some("1a24920"));
+
+        std::fs::write(git.join("HEAD"), "173dd3fbeef\n").unwrap();
+        assert_eq!(git_revision(&root).as_deref(), Some("173dd3f"));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
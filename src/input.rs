@@ -0,0 +1,230 @@
+//! Load puzzle inputs at runtime.
+//!
+//! Input of day `N` is `data/NN.txt`. Some inputs are split into several files named `NN_01.txt`,
+//! `NN_02.txt`, ..., these parts are joined with an empty line, the same layout as the original
+//! puzzle input.
+//!
+//! Set `AOC_DATA_DIR` to load inputs from another directory.
+//!
+//! With feature `embed-inputs` enabled, inputs are embedded at compile time instead.
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::Solution;
+
+/// Environment variable to override the data directory.
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+/// Default data directory.
+pub const DEFAULT_DATA_DIR: &str = "data";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// Neither `NN.txt` nor `NN_01.txt` exists.
+    NotFound { day: u8, path: PathBuf },
+
+    /// Failed to read an existing input file.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, path } => write!(
+                f,
+                "input of day {day} not found: {} does not exist",
+                path.display()
+            ),
+            InputError::Io { path, source } => {
+                write!(f, "failed to read {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+/// The data directory in use.
+pub fn data_dir() -> PathBuf {
+    std::env::var_os(DATA_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_DATA_DIR))
+}
+
+/// Path of the input file of `day`, or the `part`-th file if the input is split.
+pub fn input_path(dir: &Path, day: u8, part: Option<u8>) -> PathBuf {
+    match part {
+        Some(part) => dir.join(format!("{day:02}_{part:02}.txt")),
+        None => dir.join(format!("{day:02}.txt")),
+    }
+}
+
+/// Load input of `day`.
+#[cfg(not(feature = "embed-inputs"))]
+pub fn load(day: u8) -> Result<String, InputError> {
+    load_from(&data_dir(), day)
+}
+
+/// Load input of `day`.
+#[cfg(feature = "embed-inputs")]
+pub fn load(day: u8) -> Result<String, InputError> {
+    match embedded::input(day) {
+        Some(v) => Ok(v),
+        None => Err(InputError::NotFound {
+            day,
+            path: input_path(Path::new(DEFAULT_DATA_DIR), day, None),
+        }),
+    }
+}
+
+/// Load input of `day` from `dir`.
+pub fn load_from(dir: &Path, day: u8) -> Result<String, InputError> {
+    let path = input_path(dir, day, None);
+    if path.exists() {
+        return read(&path);
+    }
+
+    let mut parts = vec![];
+    for part in 1.. {
+        let path = input_path(dir, day, Some(part));
+        if !path.exists() {
+            break;
+        }
+        parts.push(read(&path)?);
+    }
+
+    if parts.is_empty() {
+        return Err(InputError::NotFound { day, path });
+    }
+
+    Ok(join_parts(parts.iter().map(String::as_str)))
+}
+
+/// Parts of a split input without their trailing newlines, joined with an empty line.
+fn join_parts<'a>(parts: impl IntoIterator<Item = &'a str>) -> String {
+    parts
+        .into_iter()
+        .map(|x| x.trim_end_matches(['\n', '\r']))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path)
+        .map(|x| x.trim_end_matches(['\n', '\r']).to_owned())
+        .map_err(|source| InputError::Io {
+            path: path.to_owned(),
+            source,
+        })
+}
+
+/// Load input of `S` and print answers of both parts.
+///
+/// Exit the process if the input is not available.
+pub fn run<S: Solution>() {
+    match load(S::DAY) {
+        Ok(input) => {
+            if let Err(e) = S::run(&input) {
+                eprintln!("day {}: {e}", S::DAY);
+                std::process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(feature = "embed-inputs")]
+mod embedded {
+    /// Files of an input, more than one if split.
+    macro_rules! embed {
+        ($($name:literal),+) => {
+            [$(include_str!(concat!("../data/", $name, ".txt"))),+]
+        };
+    }
+
+    pub fn input(day: u8) -> Option<String> {
+        let input: &[&str] = match day {
+            1 => &embed!("01"),
+            2 => &embed!("02"),
+            3 => &embed!("03"),
+            4 => &embed!("04"),
+            5 => &embed!("05_01", "05_02"),
+            6 => &embed!("06"),
+            7 => &embed!("07"),
+            8 => &embed!("08"),
+            9 => &embed!("09"),
+            10 => &embed!("10"),
+            11 => &embed!("11"),
+            12 => &embed!("12"),
+            13 => &embed!("13"),
+            14 => &embed!("14"),
+            15 => &embed!("15_01", "15_02"),
+            16 => &embed!("16"),
+            _ => return None,
+        };
+        Some(super::join_parts(input.iter().copied()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temp_data_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc2024-input-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_single_file() {
+        let dir = temp_data_dir("single");
+        std::fs::write(dir.join("03.txt"), "mul(2,4)\n").unwrap();
+        assert_eq!(load_from(&dir, 3).unwrap(), "mul(2,4)");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_split_files() {
+        let dir = temp_data_dir("split");
+        std::fs::write(dir.join("05_01.txt"), "47|53\n97|13\n").unwrap();
+        std::fs::write(dir.join("05_02.txt"), "75,47,61\n").unwrap();
+        assert_eq!(load_from(&dir, 5).unwrap(), "47|53\n97|13\n\n75,47,61");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_join_parts() {
+        assert_eq!(join_parts(["47|53\n", "75,47,61\r\n"]), "47|53\n\n75,47,61");
+    }
+
+    /// Embedded inputs are the ones loaded at runtime, also when split.
+    #[cfg(feature = "embed-inputs")]
+    #[test]
+    fn test_embedded_split_input() {
+        let runtime = load_from(Path::new(DEFAULT_DATA_DIR), 5).unwrap();
+        assert_eq!(embedded::input(5).unwrap(), runtime);
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let dir = temp_data_dir("missing");
+        let err = load_from(&dir, 7).unwrap_err();
+        assert!(matches!(err, InputError::NotFound { day: 7, .. }));
+        assert!(err.to_string().contains("07.txt"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,248 @@
+//! Journal of submitted guesses, so that a rejected answer is not submitted twice.
+//!
+//! Guesses are personal, they are saved in `guesses.txt` in the data directory, one guess per
+//! line in the order submitted:
+//!
+//! ```text
+//! # day part verdict answer
+//! 06 2 high 1834
+//! 06 2 correct 1753
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::line_file;
+use crate::runner::{DayReport, Outcome};
+use crate::Part;
+
+/// Name of the journal file in the data directory.
+pub const JOURNAL_FILE: &str = "guesses.txt";
+
+/// Path of the journal file in use.
+pub fn journal_path() -> PathBuf {
+    crate::input::data_dir().join(JOURNAL_FILE)
+}
+
+/// What the puzzle site answered to a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+
+    /// Wrong without a hint.
+    Wrong,
+}
+
+impl Feedback {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Feedback::Correct => "correct",
+            Feedback::TooHigh => "high",
+            Feedback::TooLow => "low",
+            Feedback::Wrong => "wrong",
+        }
+    }
+}
+
+impl FromStr for Feedback {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Feedback::Correct),
+            "high" => Ok(Feedback::TooHigh),
+            "low" => Ok(Feedback::TooLow),
+            "wrong" => Ok(Feedback::Wrong),
+            _ => Err(format!(
+                "unknown verdict {s}, expected correct, high, low or wrong"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "correct"),
+            Feedback::TooHigh => write!(f, "too high"),
+            Feedback::TooLow => write!(f, "too low"),
+            Feedback::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub answer: String,
+    pub feedback: Feedback,
+}
+
+/// Guesses of all days.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Journal {
+    guesses: BTreeMap<(u8, Part), Vec<Guess>>,
+}
+
+impl Journal {
+    /// Parse journal file content, a [`line_file`].
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut journal = Self::default();
+        for (number, line) in line_file::entries(content) {
+            let invalid = || format!("invalid guess at line {number}: {line:?}");
+            let mut fields = line.splitn(4, char::is_whitespace);
+            let day = fields
+                .next()
+                .and_then(|x| x.parse::<u8>().ok())
+                .ok_or_else(invalid)?;
+            let part = fields
+                .next()
+                .and_then(|x| x.parse::<u8>().ok())
+                .and_then(Part::from_number)
+                .ok_or_else(invalid)?;
+            let feedback = fields
+                .next()
+                .and_then(|x| x.parse::<Feedback>().ok())
+                .ok_or_else(invalid)?;
+            let answer = fields
+                .next()
+                .map(|x| x.trim())
+                .filter(|x| !x.is_empty())
+                .ok_or_else(invalid)?;
+            journal.record(day, part, answer.to_owned(), feedback);
+        }
+
+        Ok(journal)
+    }
+
+    /// Load the journal from `path`, a missing file has no guess.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        line_file::load(path, Self::parse)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    /// Guesses of a part in the order submitted.
+    pub fn guesses(&self, day: u8, part: Part) -> &[Guess] {
+        self.guesses.get(&(day, part)).map_or(&[], |x| x.as_slice())
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: String, feedback: Feedback) {
+        self.guesses
+            .entry((day, part))
+            .or_default()
+            .push(Guess { answer, feedback });
+    }
+
+    /// Why `answer` can't be correct according to earlier guesses, `None` if it may be.
+    ///
+    /// An answer is ruled out if it was rejected, differs from the correct guess, or is out of
+    /// the bounds given by too high and too low guesses. Bounds only apply to integers.
+    pub fn rule_out(&self, day: u8, part: Part, answer: &str) -> Option<String> {
+        let guesses = self.guesses(day, part);
+        if let Some(guess) = guesses.iter().find(|x| x.answer == answer) {
+            return match guess.feedback {
+                Feedback::Correct => None,
+                feedback => Some(format!("{answer} was already guessed, {feedback}")),
+            };
+        }
+        if let Some(guess) = guesses.iter().find(|x| x.feedback == Feedback::Correct) {
+            return Some(format!(
+                "{answer} differs from {}, which is correct",
+                guess.answer
+            ));
+        }
+
+        let value = answer.parse::<i128>().ok()?;
+        let bound = |feedback| {
+            guesses
+                .iter()
+                .filter(move |x| x.feedback == feedback)
+                .filter_map(|x| x.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Feedback::TooHigh).min().filter(|x| value >= *x) {
+            return Some(format!("{answer} is at or above {high}, which is too high"));
+        }
+        if let Some(low) = bound(Feedback::TooLow).max().filter(|x| value <= *x) {
+            return Some(format!("{answer} is at or below {low}, which is too low"));
+        }
+        None
+    }
+}
+
+impl fmt::Display for Journal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part verdict answer")?;
+        for ((day, part), guesses) in self.guesses.iter() {
+            for guess in guesses {
+                writeln!(
+                    f,
+                    "{day:02} {} {} {}",
+                    part.number(),
+                    guess.feedback.name(),
+                    guess.answer
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Warnings of solved parts in `report` that `journal` rules out.
+pub fn check(report: &DayReport, journal: &Journal) -> Vec<String> {
+    report
+        .parts
+        .iter()
+        .filter_map(|part| match &part.outcome {
+            Outcome::Solved(answer) => journal
+                .rule_out(report.day, part.part, &answer.to_string())
+                .map(|why| format!("day {:02} {}: {why}", report.day, part.part)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_journal() {
+        let journal = Journal::parse("# comment\n06 2 high 1834\n\n6 2 low 12\n").unwrap();
+        assert_eq!(journal.guesses(6, Part::Two).len(), 2);
+        assert_eq!(journal.guesses(6, Part::Two)[1].feedback, Feedback::TooLow);
+        assert!(journal.guesses(6, Part::One).is_empty());
+        assert_eq!(Journal::parse(&journal.to_string()).unwrap(), journal);
+
+        assert!(Journal::parse("06 2 close 1834").is_err());
+        assert!(Journal::parse("06 2 high").is_err());
+    }
+
+    #[test]
+    fn test_rule_out() {
+        let mut journal = Journal::default();
+        journal.record(6, Part::Two, String::from("1834"), Feedback::TooHigh);
+        journal.record(6, Part::Two, String::from("1700"), Feedback::TooLow);
+        journal.record(6, Part::Two, String::from("1750"), Feedback::Wrong);
+
+        assert!(journal.rule_out(6, Part::Two, "1753").is_none());
+        assert!(journal.rule_out(6, Part::One, "1834").is_none());
+        assert_eq!(
+            journal.rule_out(6, Part::Two, "1900").unwrap(),
+            "1900 is at or above 1834, which is too high"
+        );
+        assert!(journal.rule_out(6, Part::Two, "1834").is_some());
+        assert!(journal.rule_out(6, Part::Two, "1700").is_some());
+        assert!(journal.rule_out(6, Part::Two, "1750").is_some());
+
+        journal.record(6, Part::Two, String::from("1753"), Feedback::Correct);
+        assert!(journal.rule_out(6, Part::Two, "1753").is_none());
+        assert!(journal.rule_out(6, Part::Two, "1760").is_some());
+    }
+}
@@ -0,0 +1,154 @@
+//! Minimal JSON values and serializer, enough for reports.
+//!
+//! ```text
+//! let value = json::object([("day", Json::from(6u8)), ("answer", Json::from("41"))]);
+//! assert_eq!(value.to_string(), r#"{"day":6,"answer":"41"}"#);
+//! ```
+
+use std::fmt::{self, Write};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+
+    /// Written as `null` if not finite.
+    Float(f64),
+    String(String),
+    Array(Vec<Json>),
+
+    /// Fields are written in order.
+    Object(Vec<(String, Json)>),
+}
+
+/// Object with `fields` in order.
+pub fn object<'a>(fields: impl IntoIterator<Item = (&'a str, Json)>) -> Json {
+    Json::Object(
+        fields
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value))
+            .collect(),
+    )
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(v) => write!(f, "{v}"),
+            Json::Int(v) => write!(f, "{v}"),
+            Json::UInt(v) => write!(f, "{v}"),
+            Json::Float(v) if v.is_finite() => write!(f, "{v}"),
+            Json::Float(_) => f.write_str("null"),
+            Json::String(v) => write_string(f, v),
+            Json::Array(items) => {
+                f.write_char('[')?;
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_char(']')
+            }
+            Json::Object(fields) => {
+                f.write_char('{')?;
+                for (idx, (key, value)) in fields.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+macro_rules! json_from {
+    ($variant:ident, $target:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Json {
+                fn from(value: $t) -> Self {
+                    Json::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+json_from!(Int, i64, i8, i16, i32, i64, isize);
+json_from!(UInt, u64, u8, u16, u32, u64, usize);
+json_from!(Float, f64, f32, f64);
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_owned())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(value: Vec<T>) -> Self {
+        Json::Array(value.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let value = object([
+            ("day", Json::from(6u8)),
+            ("answer", Json::from("say \"hi\"\n\u{1}")),
+            ("delta", Json::from(-3)),
+            ("ms", Json::from(1.5)),
+            ("nan", Json::from(f64::NAN)),
+            ("error", Json::from(None::<String>)),
+            ("ok", Json::from(vec![true, false])),
+        ]);
+        assert_eq!(
+            value.to_string(),
+            r#"{"day":6,"answer":"say \"hi\"\n\u0001","delta":-3,"ms":1.5,"nan":null,"error":null,"ok":[true,false]}"#
+        );
+        assert_eq!(Json::Array(vec![]).to_string(), "[]");
+    }
+}
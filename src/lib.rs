@@ -1 +1,31 @@
+pub mod animate;
+pub mod answers;
+pub mod bench;
+pub mod checked;
+pub mod components;
+pub mod cpu;
+pub mod days;
+pub mod differential;
+pub mod error;
+pub mod fixtures;
+pub mod geometry;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod journal;
+pub mod json;
+pub mod line_file;
+pub mod memory;
+pub mod parse;
+pub mod render;
+pub mod rng;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod trace;
+pub mod watch;
+
+pub use solution::{Answer, Part, Puzzle, Solution};
+
 pub type RawData = &'static str;
@@ -0,0 +1,58 @@
+//! Files of one entry per line, such as answers, guesses and benchmark history.
+//!
+//! Empty lines and lines starting with `#` are comments:
+//!
+//! ```text
+//! # day part answer
+//! 01 1 1530215
+//! ```
+
+use std::io;
+use std::path::Path;
+
+/// Trimmed lines that are not comments, with their line number counted from 1.
+pub fn entries(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Read the file at `path` and `parse` it, a missing file is `T::default()`.
+///
+/// Parse errors are [`io::ErrorKind::InvalidData`].
+pub fn load<T: Default>(
+    path: &Path,
+    parse: impl FnOnce(&str) -> Result<T, String>,
+) -> io::Result<T> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => parse(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_entries() {
+        let content = "# header\n\n  a 1  \n#b 2\nc 3\n";
+        assert_eq!(
+            entries(content).collect::<Vec<_>>(),
+            [(3, "a 1"), (5, "c 3")]
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let path = std::env::temp_dir().join(format!("aoc2024-line-file-{}", std::process::id()));
+        assert_eq!(load(&path, |_| Ok(1u8)).unwrap(), 0);
+        std::fs::write(&path, "x\n").unwrap();
+        let e = load::<u8>(&path, |_| Err(String::from("bad"))).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,257 @@
+//! Heap accounting, allocations, bytes and peak heap of running a closure.
+//!
+//! Counting needs the allocator of this module, a binary opts in with:
+//!
+//! ```text
+//! #[global_allocator]
+//! static ALLOC: CountingAlloc = CountingAlloc;
+//! ```
+//!
+//! `aoc` opts in with the `count-alloc` feature.
+//!
+//! Counters are kept per thread, so that days run concurrently are measured apart. Memory
+//! allocated on one thread and freed on another is not accounted correctly.
+//!
+//! Enforcement of the budget is cooperative, the allocator can not interrupt a solver. A part over
+//! the budget fails when it returns, long running solvers call [`check_budget`] to give up early. A
+//! part that does neither is not stopped.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::error::{AocError, AocResult};
+
+/// [`System`] allocator counting allocations of each thread.
+pub struct CountingAlloc;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Budget in bytes, zero for none.
+static BUDGET: AtomicUsize = AtomicUsize::new(0);
+
+struct Counters {
+    allocations: Cell<u64>,
+    bytes: Cell<u64>,
+
+    /// Bytes allocated minus bytes freed on this thread, negative if others were freed.
+    current: Cell<isize>,
+    peak: Cell<isize>,
+
+    /// `current` when the innermost [`measure`] started.
+    base: Cell<isize>,
+}
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters {
+            allocations: Cell::new(0),
+            bytes: Cell::new(0),
+            current: Cell::new(0),
+            peak: Cell::new(0),
+            base: Cell::new(0),
+        }
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    // Fails only while the thread is being torn down, such memory is not accounted.
+    let _ = COUNTERS.try_with(|x| {
+        if allocated > 0 {
+            x.allocations.set(x.allocations.get() + 1);
+            x.bytes.set(x.bytes.get() + allocated as u64);
+        }
+        let current = x.current.get() + allocated as isize - freed as isize;
+        x.current.set(current);
+        x.peak.set(x.peak.get().max(current));
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    /// Counted as an allocation of `new_size` bytes.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Whether [`CountingAlloc`] is the global allocator.
+pub fn installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Heap usage of a measured closure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Usage {
+    pub allocations: u64,
+
+    /// Bytes allocated, including those freed again.
+    pub bytes: u64,
+
+    /// Most bytes held at once, above what was held before.
+    pub peak: usize,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} total, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak as u64)
+        )
+    }
+}
+
+/// Run `f` and count what it allocates on this thread, `None` without [`CountingAlloc`].
+///
+/// Measures can be nested, the outer one includes the inner one.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !installed() {
+        return (f(), None);
+    }
+
+    let (allocations, bytes, outer_base, outer_peak) = COUNTERS.with(|x| {
+        let saved = (
+            x.allocations.get(),
+            x.bytes.get(),
+            x.base.get(),
+            x.peak.get(),
+        );
+        x.base.set(x.current.get());
+        x.peak.set(x.current.get());
+        saved
+    });
+    let value = f();
+    let usage = COUNTERS.with(|x| {
+        let usage = Usage {
+            allocations: x.allocations.get() - allocations,
+            bytes: x.bytes.get() - bytes,
+            peak: (x.peak.get() - x.base.get()).max(0) as usize,
+        };
+        x.base.set(outer_base);
+        x.peak.set(x.peak.get().max(outer_peak));
+        usage
+    });
+    (value, Some(usage))
+}
+
+/// Limit the peak of parts run after this, `None` for no limit.
+pub fn set_budget(budget: Option<usize>) {
+    BUDGET.store(budget.unwrap_or(0), Ordering::Relaxed);
+}
+
+pub fn budget() -> Option<usize> {
+    Some(BUDGET.load(Ordering::Relaxed)).filter(|x| *x > 0)
+}
+
+/// Error if `peak` is over `budget`.
+pub fn over_budget(peak: usize, budget: Option<usize>) -> Option<AocError> {
+    let budget = budget?;
+    (peak > budget).then(|| {
+        AocError::Budget(format!(
+            "peak {} over {}",
+            format_bytes(peak as u64),
+            format_bytes(budget as u64)
+        ))
+    })
+}
+
+/// Give up if the running [`measure`] went over the budget so far.
+///
+/// Cheap, call it once per round of a long search.
+pub fn check_budget() -> AocResult<()> {
+    if !installed() {
+        return Ok(());
+    }
+    let peak = COUNTERS.with(|x| (x.peak.get() - x.base.get()).max(0) as usize);
+    match over_budget(peak, budget()) {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// `bytes` in binary units, e.g. `1.50 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.2} {}", UNITS[unit])
+}
+
+/// Size in bytes with an optional binary suffix, e.g. `4096`, `512K`, `64M` or `2G`.
+pub fn parse_size(s: &str) -> Result<usize, String> {
+    let (digits, shift) = match s.char_indices().last() {
+        Some((idx, 'k' | 'K')) => (&s[..idx], 10),
+        Some((idx, 'm' | 'M')) => (&s[..idx], 20),
+        Some((idx, 'g' | 'G')) => (&s[..idx], 30),
+        _ => (s, 0),
+    };
+    digits
+        .parse::<usize>()
+        .ok()
+        .and_then(|x| x.checked_mul(1 << shift))
+        .ok_or_else(|| format!("invalid size {s}, expected bytes or a number with K, M or G"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_format_and_parse_size() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.00 GiB");
+        assert_eq!(parse_size("4096"), Ok(4096));
+        assert_eq!(parse_size("64M"), Ok(64 << 20));
+        assert_eq!(parse_size("2g"), Ok(2 << 30));
+        assert!(parse_size("M").is_err());
+        assert!(parse_size("1.5M").is_err());
+    }
+
+    #[test]
+    fn test_over_budget() {
+        assert!(over_budget(2048, None).is_none());
+        assert!(over_budget(2048, Some(2048)).is_none());
+        assert_eq!(
+            over_budget(2049, Some(2048)).unwrap().to_string(),
+            "memory budget exceeded: peak 2.00 KiB over 2.00 KiB"
+        );
+    }
+}
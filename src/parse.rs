@@ -0,0 +1,303 @@
+//! Parser combinators for puzzle inputs.
+//!
+//! A parser takes the input and returns the parsed value with the rest of input, or a [`Failure`]
+//! where it stops. Small parsers are combined into the parser of a whole input, then
+//! [`parse_all`] runs it and reports errors with line and column:
+//!
+//! ```text
+//! // 47|53
+//! let rule = separated_pair(uint::<usize>(), literal("|"), uint());
+//! let rules: Vec<(usize, usize)> = parse_all(lines(rule), input)?;
+//! ```
+//!
+//! A parser fails without consuming input is a soft failure, repetitions like [`separated`] stop
+//! there. Failures after consuming input are reported.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Parsed value with the rest of input.
+pub type PResult<'a, T> = Result<(T, &'a str), Failure>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    /// What the parser expected.
+    pub expected: String,
+
+    /// Length of the input left where the parser stops.
+    pub remaining: usize,
+}
+
+impl Failure {
+    pub fn new(expected: impl Into<String>, rest: &str) -> Self {
+        Self {
+            expected: expected.into(),
+            remaining: rest.len(),
+        }
+    }
+}
+
+/// Error of parsing a whole input, line and column start from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+
+    /// The line where error occurs.
+    pub snippet: String,
+}
+
+impl ParseError {
+    /// Error at byte `offset` of `input`.
+    pub fn at(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |x| x + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |x| offset + x);
+        Self {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            message: message.into(),
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} in {:?}",
+            self.line, self.column, self.message, self.snippet
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub trait Parser<T> {
+    fn parse<'a>(&self, input: &'a str) -> PResult<'a, T>;
+}
+
+impl<T, F> Parser<T> for F
+where
+    F: for<'a> Fn(&'a str) -> PResult<'a, T>,
+{
+    fn parse<'a>(&self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Make a parser from a closure, helps the compiler infer lifetimes.
+pub fn from_fn<T, F>(f: F) -> F
+where
+    F: for<'a> Fn(&'a str) -> PResult<'a, T>,
+{
+    f
+}
+
+/// Run `parser` on the whole `input`, trailing newlines are ignored.
+pub fn parse_all<T>(parser: impl Parser<T>, input: &str) -> Result<T, ParseError> {
+    let trimmed = input.trim_end_matches(['\n', '\r']);
+    match parser.parse(trimmed) {
+        Ok((value, "")) => Ok(value),
+        Ok((_, rest)) => {
+            // Point at the unexpected text instead of the line break before it.
+            let offset = trimmed.len() - rest.trim_start_matches(['\n', '\r']).len();
+            Err(ParseError::at(input, offset, "unexpected input"))
+        }
+        Err(failure) => Err(ParseError::at(
+            input,
+            trimmed.len() - failure.remaining,
+            format!("expected {}", failure.expected),
+        )),
+    }
+}
+
+pub fn literal(s: &'static str) -> impl Parser<()> {
+    from_fn(move |input| match input.strip_prefix(s) {
+        Some(rest) => Ok(((), rest)),
+        None => Err(Failure::new(format!("{s:?}"), input)),
+    })
+}
+
+/// One or more spaces or tabs.
+pub fn spaces() -> impl Parser<()> {
+    from_fn(|input: &str| {
+        let rest = input.trim_start_matches([' ', '\t']);
+        if rest.len() == input.len() {
+            Err(Failure::new("spaces", input))
+        } else {
+            Ok(((), rest))
+        }
+    })
+}
+
+fn digits<'a, T: FromStr>(input: &'a str, sign: usize, expected: &str) -> PResult<'a, T> {
+    let end = input[sign..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(input.len(), |x| sign + x);
+    if end == sign {
+        return Err(Failure::new(expected, input));
+    }
+    match input[..end].parse() {
+        Ok(value) => Ok((value, &input[end..])),
+        Err(_) => Err(Failure::new(format!("{expected} in range"), input)),
+    }
+}
+
+/// Unsigned integer, digits only.
+pub fn uint<T: FromStr>() -> impl Parser<T> {
+    from_fn(|input| digits(input, 0, "unsigned integer"))
+}
+
+/// Integer with an optional `+` or `-` sign.
+pub fn int<T: FromStr>() -> impl Parser<T> {
+    from_fn(|input: &str| {
+        let sign = usize::from(input.starts_with(['+', '-']));
+        digits(input, sign, "integer")
+    })
+}
+
+pub fn map<T, U>(parser: impl Parser<T>, f: impl Fn(T) -> U) -> impl Parser<U> {
+    from_fn(move |input| {
+        let (value, rest) = parser.parse(input)?;
+        Ok((f(value), rest))
+    })
+}
+
+pub fn pair<A, B>(a: impl Parser<A>, b: impl Parser<B>) -> impl Parser<(A, B)> {
+    from_fn(move |input| {
+        let (x, rest) = a.parse(input)?;
+        let (y, rest) = b.parse(rest)?;
+        Ok(((x, y), rest))
+    })
+}
+
+/// Parse `a` and `b` separated by `sep`.
+pub fn separated_pair<A, B, S>(
+    a: impl Parser<A>,
+    sep: impl Parser<S>,
+    b: impl Parser<B>,
+) -> impl Parser<(A, B)> {
+    from_fn(move |input| {
+        let (x, rest) = a.parse(input)?;
+        let (_, rest) = sep.parse(rest)?;
+        let (y, rest) = b.parse(rest)?;
+        Ok(((x, y), rest))
+    })
+}
+
+/// Parse `prefix` then `parser`, keep the latter.
+pub fn preceded<P, T>(prefix: impl Parser<P>, parser: impl Parser<T>) -> impl Parser<T> {
+    from_fn(move |input| {
+        let (_, rest) = prefix.parse(input)?;
+        parser.parse(rest)
+    })
+}
+
+/// Parse `parser` then `suffix`, keep the former.
+pub fn terminated<T, S>(parser: impl Parser<T>, suffix: impl Parser<S>) -> impl Parser<T> {
+    from_fn(move |input| {
+        let (value, rest) = parser.parse(input)?;
+        let (_, rest) = suffix.parse(rest)?;
+        Ok((value, rest))
+    })
+}
+
+/// `key=value`.
+pub fn field<T>(key: &'static str, value: impl Parser<T>) -> impl Parser<T> {
+    preceded(pair(literal(key), literal("=")), value)
+}
+
+/// One or more `item`s separated by `sep`.
+///
+/// Stops before a separator if the next item fails without consuming input.
+pub fn separated<T, S>(item: impl Parser<T>, sep: impl Parser<S>) -> impl Parser<Vec<T>> {
+    from_fn(move |input| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+        loop {
+            let Ok((_, after_sep)) = sep.parse(rest) else {
+                break;
+            };
+            match item.parse(after_sep) {
+                Ok((value, after_item)) => {
+                    items.push(value);
+                    rest = after_item;
+                }
+                Err(failure) if failure.remaining == after_sep.len() => break,
+                Err(failure) => return Err(failure),
+            }
+        }
+        Ok((items, rest))
+    })
+}
+
+/// One `item` per line.
+pub fn lines<T>(item: impl Parser<T>) -> impl Parser<Vec<T>> {
+    separated(item, literal("\n"))
+}
+
+/// Blocks separated by blank lines.
+pub fn blocks<T>(block: impl Parser<T>) -> impl Parser<Vec<T>> {
+    separated(block, literal("\n\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(parse_all(uint::<u8>(), "42\n"), Ok(42));
+        assert_eq!(parse_all(int::<i32>(), "-42"), Ok(-42));
+        assert_eq!(parse_all(int::<i32>(), "+42"), Ok(42));
+        assert!(parse_all(uint::<u32>(), "-1").is_err());
+        assert!(parse_all(int::<i32>(), "-").is_err());
+
+        let err = parse_all(uint::<u8>(), "256").unwrap_err();
+        assert_eq!(err.message, "expected unsigned integer in range");
+    }
+
+    #[test]
+    fn test_lines_and_blocks() {
+        let rule = separated_pair(uint::<u32>(), literal("|"), uint::<u32>());
+        let update = separated(uint::<u32>(), literal(","));
+        let parser = separated_pair(lines(rule), literal("\n\n"), lines(update));
+        let (rules, updates) = parse_all(parser, "47|53\n97|13\n\n75,47\n97,61,53\n").unwrap();
+        assert_eq!(rules, [(47, 53), (97, 13)]);
+        assert_eq!(updates, [vec![75, 47], vec![97, 61, 53]]);
+
+        let blocks = parse_all(blocks(lines(uint::<u8>())), "1\n2\n\n3").unwrap();
+        assert_eq!(blocks, [vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_fields() {
+        let xy = || separated_pair(int::<i32>(), literal(","), int::<i32>());
+        let robot = separated_pair(field("p", xy()), spaces(), field("v", xy()));
+        assert_eq!(parse_all(robot, "p=0,4 v=3,-3"), Ok(((0, 4), (3, -3))));
+    }
+
+    #[test]
+    fn test_error_position() {
+        let parser = lines(separated_pair(uint::<u32>(), spaces(), uint::<u32>()));
+        let err = parse_all(parser, "1   2\n3   x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.message, "expected unsigned integer");
+        assert_eq!(err.snippet, "3   x");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 5: expected unsigned integer in \"3   x\""
+        );
+
+        let err = parse_all(lines(uint::<u32>()), "1\n2\nx").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.message, "unexpected input");
+    }
+}
@@ -0,0 +1,188 @@
+//! Render grids to images, binary PPM (color) or PGM (gray) with no dependencies.
+//!
+//! ```text
+//! let image = Image::from_grid(&world, |ch| Palette::default().color(*ch)).scaled(4);
+//! image.save("world.ppm")?;
+//! ```
+//!
+//! Simulations dump a frame per step through [`Solution::frames`](crate::Solution::frames), see
+//! `aoc render`.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// Colors of tiles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+}
+
+impl Palette {
+    /// Palette without any tile color, all tiles get a color derived from the tile itself.
+    pub fn empty() -> Self {
+        Self {
+            colors: HashMap::new(),
+        }
+    }
+
+    pub fn with(mut self, tile: char, color: Rgb) -> Self {
+        self.colors.insert(tile, color);
+        self
+    }
+
+    /// Color of `tile`, tiles not in the palette get a stable color of their own.
+    pub fn color(&self, tile: char) -> Rgb {
+        if let Some(color) = self.colors.get(&tile) {
+            return *color;
+        }
+        // Spread neighbouring chars like 'A' and 'B' apart.
+        let hash = (tile as u32).wrapping_mul(2654435761);
+        [(hash >> 24) as u8, (hash >> 16) as u8, (hash >> 8) as u8].map(|x| x / 2 + 64)
+    }
+}
+
+impl Default for Palette {
+    /// Tiles used by most puzzles: walls, empty space, boxes, robots and the guard.
+    fn default() -> Self {
+        Self::empty()
+            .with('#', [96, 96, 96])
+            .with('.', [16, 16, 16])
+            .with('O', [200, 140, 40])
+            .with('[', [200, 140, 40])
+            .with(']', [170, 110, 20])
+            .with('@', [40, 200, 80])
+            .with('X', [40, 80, 160])
+            .with('^', [230, 60, 60])
+            .with('>', [230, 60, 60])
+            .with('v', [230, 60, 60])
+            .with('<', [230, 60, 60])
+    }
+}
+
+/// RGB image, one pixel per tile before scaling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> Self {
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.iter().map(|(_, x)| color(x)).collect(),
+        }
+    }
+
+    /// Tiles of a char grid in `palette`.
+    pub fn from_tiles(grid: &Grid<char>, palette: &Palette) -> Self {
+        Self::from_grid(grid, |x| palette.color(*x))
+    }
+
+    /// `points` in white on black, points outside are ignored.
+    pub fn from_points(
+        points: impl IntoIterator<Item = Point>,
+        width: usize,
+        height: usize,
+    ) -> Self {
+        let mut grid = Grid::new(width, height, BLACK);
+        for p in points {
+            if let Some(x) = grid.get_mut(p) {
+                *x = WHITE;
+            }
+        }
+        Self::from_grid(&grid, |x| *x)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Every pixel becomes a `factor`×`factor` square.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let width = self.width * factor;
+        let height = self.height * factor;
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Binary PPM (P6).
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(self.pixels.iter().flatten());
+        out
+    }
+
+    /// Binary PGM (P5), colors are converted to luma.
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut out = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(
+            self.pixels.iter().map(|[r, g, b]| {
+                ((299 * *r as u32 + 587 * *g as u32 + 114 * *b as u32) / 1000) as u8
+            }),
+        );
+        out
+    }
+
+    /// Write to `path`, PGM if the extension is `pgm`, PPM otherwise.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let data = match path.extension() {
+            Some(x) if x == "pgm" => self.to_pgm(),
+            _ => self.to_ppm(),
+        };
+        fs::write(path, data)
+    }
+}
+
+/// Path of frame `step` in `dir`, numbered so that files sort by step.
+pub fn frame_path(dir: &Path, step: usize, extension: &str) -> PathBuf {
+    dir.join(format!("frame_{step:06}.{extension}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_image() {
+        let grid = Grid::parse("#.\n.@").unwrap();
+        let palette = Palette::empty().with('#', WHITE).with('.', BLACK);
+        let image = Image::from_tiles(&grid, &palette);
+        assert_eq!(&image.to_ppm()[..11], b"P6\n2 2\n255\n");
+        assert_eq!(&image.to_ppm()[11..17], [255, 255, 255, 0, 0, 0]);
+        assert_eq!(image.to_pgm().len(), 11 + 4);
+
+        let scaled = image.scaled(3);
+        assert_eq!((scaled.width(), scaled.height()), (6, 6));
+        assert_eq!(scaled.pixels[2], WHITE);
+        assert_eq!(scaled.pixels[3], BLACK);
+        assert_eq!(scaled.pixels[6 * 3], BLACK);
+
+        let points = Image::from_points([Point::new(1, 0), Point::new(5, 5)], 2, 1);
+        assert_eq!(points.to_pgm()[11..], [0, 255]);
+    }
+}
@@ -0,0 +1,682 @@
+//! Run registered puzzles and collect the results.
+//!
+//! Errors and panics in solvers are caught so that one broken part does not abort the others.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cpu;
+use crate::error::{AocError, AocResult};
+use crate::json::{self, Json};
+use crate::memory::{self, Usage};
+use crate::{Answer, Part, Puzzle};
+
+/// Result of running a single part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answer),
+
+    /// The part returned [`AocError::Unimplemented`], or panicked with `unimplemented!()` or
+    /// `todo!()`.
+    Unimplemented,
+
+    /// The part, or parsing the input, returned an error.
+    Failed(String),
+
+    /// The part, or parsing the input, panicked.
+    Panicked(String),
+
+    /// Input is not available.
+    NoInput(String),
+}
+
+impl Outcome {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Outcome::Solved(_))
+    }
+
+    /// Short name of the status.
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Solved(_) => "ok",
+            Outcome::Unimplemented => "todo",
+            Outcome::Failed(_) => "error",
+            Outcome::Panicked(_) => "panic",
+            Outcome::NoInput(_) => "no input",
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Solved(answer) => write!(f, "{answer}"),
+            Outcome::Unimplemented => write!(f, "not implemented"),
+            Outcome::Failed(msg) => write!(f, "{msg}"),
+            Outcome::Panicked(msg) => write!(f, "{msg}"),
+            Outcome::NoInput(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: Part,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+
+    /// Heap usage, if counted with [`memory::CountingAlloc`].
+    pub memory: Option<Usage>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub title: &'static str,
+    pub parse_elapsed: Duration,
+    pub parse_memory: Option<Usage>,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    /// Report of a day without input, all `parts` are [`Outcome::NoInput`].
+    pub fn no_input(puzzle: &dyn Puzzle, parts: &[Part], msg: String) -> Self {
+        Self::with_outcome(puzzle, parts, Outcome::NoInput(msg))
+    }
+
+    /// Report of a day with the same `outcome` of all `parts`, which are not run.
+    pub fn with_outcome(puzzle: &dyn Puzzle, parts: &[Part], outcome: Outcome) -> Self {
+        Self {
+            day: puzzle.day(),
+            title: puzzle.title(),
+            parse_elapsed: Duration::ZERO,
+            parse_memory: None,
+            parts: parts
+                .iter()
+                .map(|part| PartReport {
+                    part: *part,
+                    outcome: outcome.clone(),
+                    elapsed: Duration::ZERO,
+                    memory: None,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn all_solved(&self) -> bool {
+        self.parts.iter().all(|x| x.outcome.is_solved())
+    }
+}
+
+thread_local! {
+    /// Message of the last panic caught on this thread, set by the panic hook.
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Replace the default panic hook with one that records the message instead of printing it.
+///
+/// Messages are reported by [`catch`], call this once before running puzzles to keep the output
+/// clean.
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        panic::set_hook(Box::new(|info| {
+            let msg = payload_message(info.payload());
+            let msg = match info.location() {
+                Some(loc) => format!("{msg} at {}:{}", loc.file(), loc.line()),
+                None => msg,
+            };
+            LAST_PANIC.with(|x| *x.borrow_mut() = Some(msg));
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(v) = payload.downcast_ref::<&str>() {
+        v.to_string()
+    } else if let Some(v) = payload.downcast_ref::<String>() {
+        v.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+/// Run `f`, turning a panic into [`Outcome::Panicked`] or [`Outcome::Unimplemented`].
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Outcome> {
+    LAST_PANIC.with(|x| x.borrow_mut().take());
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let raw = payload_message(payload.as_ref());
+        if raw.starts_with("not implemented") || raw.starts_with("not yet implemented") {
+            return Outcome::Unimplemented;
+        }
+        let msg = LAST_PANIC.with(|x| x.borrow_mut().take()).unwrap_or(raw);
+        Outcome::Panicked(msg)
+    })
+}
+
+impl From<AocError> for Outcome {
+    fn from(e: AocError) -> Self {
+        match e {
+            AocError::Unimplemented => Outcome::Unimplemented,
+            e => Outcome::Failed(e.to_string()),
+        }
+    }
+}
+
+/// Run fallible `f`, turning both errors and panics into [`Outcome`].
+pub fn catch_result<T>(f: impl FnOnce() -> AocResult<T>) -> Result<T, Outcome> {
+    catch(f)?.map_err(Outcome::from)
+}
+
+/// Parse `input` and run `parts` of `puzzle`.
+///
+/// A part that solves with a heap peak over [`memory::budget`] fails.
+pub fn run_day(puzzle: &dyn Puzzle, input: &str, parts: &[Part]) -> DayReport {
+    let start = Instant::now();
+    let (parsed, parse_memory) = memory::measure(|| catch_result(|| puzzle.parse(input)));
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|part| match &parsed {
+            Ok(parsed) => {
+                let start = Instant::now();
+                let (result, usage) =
+                    memory::measure(|| catch_result(|| puzzle.solve(*part, parsed.as_ref())));
+                let elapsed = start.elapsed();
+                let over_budget = usage.and_then(|x| memory::over_budget(x.peak, memory::budget()));
+                let outcome = match (result, over_budget) {
+                    (Ok(_), Some(e)) => e.into(),
+                    (Ok(answer), None) => Outcome::Solved(answer),
+                    (Err(outcome), _) => outcome,
+                };
+                PartReport {
+                    part: *part,
+                    outcome,
+                    elapsed,
+                    memory: usage,
+                }
+            }
+            Err(outcome) => PartReport {
+                part: *part,
+                outcome: match outcome {
+                    Outcome::Panicked(msg) => Outcome::Panicked(format!("parse: {msg}")),
+                    v => v.clone(),
+                },
+                elapsed: Duration::ZERO,
+                memory: None,
+            },
+        })
+        .collect();
+
+    DayReport {
+        day: puzzle.day(),
+        title: puzzle.title(),
+        parse_elapsed,
+        parse_memory,
+        parts,
+    }
+}
+
+/// Reports of days run concurrently.
+#[derive(Debug, Clone)]
+pub struct RunSummary {
+    /// Reports in the order of days given.
+    pub reports: Vec<DayReport>,
+
+    /// Time from start to the last day finished.
+    pub wall: Duration,
+
+    /// CPU time of all days summed, `None` where [`cpu::thread_time`] is not available.
+    pub cpu: Option<Duration>,
+
+    pub jobs: usize,
+}
+
+impl fmt::Display for RunSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "wall {:.2?}, ", self.wall)?;
+        if let Some(cpu) = self.cpu {
+            let ratio = cpu.as_secs_f64() / self.wall.as_secs_f64().max(f64::EPSILON);
+            write!(f, "cpu {cpu:.2?} ({ratio:.1}x), ")?;
+        }
+        write!(f, "jobs {}", self.jobs)
+    }
+}
+
+/// Number of jobs when not specified, one per available core.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |x| x.get())
+}
+
+/// Run `parts` of `puzzles` on `jobs` worker threads with `run`, e.g. load input and
+/// [`run_day`].
+///
+/// A panic escapes `run` is reported as [`Outcome::Panicked`] of the day, other days go on.
+pub fn run_days<F>(puzzles: &[&dyn Puzzle], parts: &[Part], jobs: usize, run: F) -> RunSummary
+where
+    F: Fn(&dyn Puzzle, &[Part]) -> DayReport + Sync,
+{
+    let jobs = jobs.clamp(1, puzzles.len().max(1));
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; puzzles.len()]);
+
+    let start = Instant::now();
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            scope.spawn(|| loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(puzzle) = puzzles.get(idx) else {
+                    break;
+                };
+                let task_start = cpu::thread_time();
+                let report = catch(|| run(*puzzle, parts))
+                    .unwrap_or_else(|outcome| DayReport::with_outcome(*puzzle, parts, outcome));
+                results.lock().unwrap()[idx] = Some((report, cpu::elapsed(task_start)));
+            });
+        }
+    });
+    let wall = start.elapsed();
+
+    let results = results.into_inner().unwrap();
+    RunSummary {
+        cpu: results.iter().flatten().map(|(_, cpu)| *cpu).sum(),
+        reports: results.into_iter().flatten().map(|(x, _)| x).collect(),
+        wall,
+        jobs,
+    }
+}
+
+/// Output format of reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Table,
+
+    /// An array of objects, one per row.
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            v => Err(format!("unknown format {v}, expected table, json or csv")),
+        }
+    }
+}
+
+/// Format reports in `format`, one row per part.
+pub fn format_reports(reports: &[DayReport], format: Format) -> String {
+    if format == Format::Table {
+        return format_table(reports);
+    }
+
+    let header = [
+        "day",
+        "title",
+        "part",
+        "status",
+        "answer",
+        "error",
+        "parse_ns",
+        "solve_ns",
+        "parse_peak_bytes",
+        "allocations",
+        "alloc_bytes",
+        "peak_bytes",
+    ];
+    let mut rows = vec![];
+    for report in reports {
+        for part in report.parts.iter() {
+            let (answer, error) = match &part.outcome {
+                Outcome::Solved(answer) => (Some(answer.to_string()), None),
+                v => (None, Some(v.to_string())),
+            };
+            rows.push([
+                Json::from(report.day),
+                Json::from(report.title),
+                Json::from(part.part.number()),
+                Json::from(part.outcome.status()),
+                Json::from(answer),
+                Json::from(error),
+                Json::from(report.parse_elapsed.as_nanos() as u64),
+                Json::from(part.elapsed.as_nanos() as u64),
+                Json::from(report.parse_memory.map(|x| x.peak as u64)),
+                Json::from(part.memory.map(|x| x.allocations)),
+                Json::from(part.memory.map(|x| x.bytes)),
+                Json::from(part.memory.map(|x| x.peak as u64)),
+            ]);
+        }
+    }
+
+    format_rows(&header, rows, format)
+}
+
+/// Format `rows` as JSON or CSV, or a table.
+pub fn format_rows<const N: usize>(
+    header: &[&str; N],
+    rows: Vec<[Json; N]>,
+    format: Format,
+) -> String {
+    if format == Format::Json {
+        let objects = rows
+            .into_iter()
+            .map(|row| json::object(header.iter().copied().zip(row)).to_string())
+            .collect::<Vec<_>>();
+        if objects.is_empty() {
+            return String::from("[]\n");
+        }
+        return format!("[\n{}\n]\n", objects.join(",\n"));
+    }
+
+    let rows = rows
+        .into_iter()
+        .map(|row| row.map(csv_cell))
+        .collect::<Vec<_>>();
+    match format {
+        Format::Csv => csv(header, &rows),
+        _ => table(header, &rows),
+    }
+}
+
+/// Plain text of a cell, `null` is empty.
+fn csv_cell(value: Json) -> String {
+    match value {
+        Json::Null => String::new(),
+        Json::String(v) => v,
+        v => v.to_string(),
+    }
+}
+
+/// Format `rows` as CSV, fields are quoted if needed.
+pub fn csv<const N: usize>(header: &[&str; N], rows: &[[String; N]]) -> String {
+    fn field(s: &str) -> String {
+        if s.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", s.replace('"', "\"\""))
+        } else {
+            s.to_owned()
+        }
+    }
+
+    let mut out = header.map(field).join(",");
+    out.push('\n');
+    for row in rows.iter() {
+        out.push_str(&row.iter().map(|x| field(x)).collect::<Vec<_>>().join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Parse CSV written by [`csv`] into records, the header included.
+pub fn parse_csv(content: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = content.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match (quoted, ch) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, ch) => field.push(ch),
+            (false, '"') if field.is_empty() => quoted = true,
+            (false, ',') => record.push(std::mem::take(&mut field)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            (false, ch) => field.push(ch),
+        }
+    }
+    if quoted {
+        return Err(String::from("unterminated quoted field"));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Format reports as a table.
+pub fn format_table(reports: &[DayReport]) -> String {
+    let header = [
+        "Day", "Title", "Part", "Status", "Answer", "Parse", "Solve", "Allocs", "Peak",
+    ];
+    let mut rows = vec![];
+    for report in reports {
+        for part in report.parts.iter() {
+            let (allocations, peak) = match part.memory {
+                Some(usage) => (
+                    usage.allocations.to_string(),
+                    memory::format_bytes(usage.peak as u64),
+                ),
+                None => (String::from("-"), String::from("-")),
+            };
+            rows.push([
+                format!("{:02}", report.day),
+                report.title.to_string(),
+                part.part.number().to_string(),
+                part.outcome.status().to_string(),
+                part.outcome.to_string(),
+                format!("{:.2?}", report.parse_elapsed),
+                format!("{:.2?}", part.elapsed),
+                allocations,
+                peak,
+            ]);
+        }
+    }
+
+    table(&header, &rows)
+}
+
+/// Format `rows` as a markdown style table.
+pub fn table<const N: usize>(header: &[&str; N], rows: &[[String; N]]) -> String {
+    let mut widths = header.map(|x| x.chars().count());
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        let cells = cells
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!(" {cell:<width$} "))
+            .collect::<Vec<_>>();
+        format!("|{}|\n", cells.join("|"))
+    };
+
+    let mut out = format_row(&header.map(String::from));
+    out.push_str(&format_row(&widths.map(|width| "-".repeat(width))));
+    for row in rows.iter() {
+        out.push_str(&format_row(row));
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse::{literal, parse_all, separated, uint};
+    use crate::Solution;
+
+    struct Dummy;
+
+    impl Solution for Dummy {
+        const DAY: u8 = 42;
+        const TITLE: &'static str = "Dummy";
+
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> AocResult<Self::Input> {
+            Ok(parse_all(separated(uint(), literal(",")), input)?)
+        }
+
+        fn part1(input: &Self::Input) -> AocResult<Answer> {
+            if input.is_empty() {
+                return Err(AocError::invalid("empty input"));
+            }
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part2(input: &Self::Input) -> AocResult<Answer> {
+            if input[0] == 0 {
+                panic!("zero");
+            }
+            Err(AocError::Unimplemented)
+        }
+    }
+
+    #[test]
+    fn test_run_day() {
+        install_panic_hook();
+        let report = run_day(&Dummy, "1,2,3", &Part::ALL);
+        assert_eq!(report.parts[0].outcome, Outcome::Solved(Answer::UInt(6)));
+        assert_eq!(report.parts[1].outcome, Outcome::Unimplemented);
+        assert!(!report.all_solved());
+    }
+
+    #[test]
+    fn test_run_day_errors() {
+        install_panic_hook();
+        let report = run_day(&Dummy, "1,x", &Part::ALL);
+        let expected = Outcome::Failed(
+            "parse error at line 1, column 2: unexpected input in \"1,x\"".to_owned(),
+        );
+        assert_eq!(report.parts[0].outcome, expected);
+        assert_eq!(report.parts[1].outcome, expected);
+        assert_eq!(report.parts[0].outcome.status(), "error");
+
+        let report = run_day(&Dummy, "0", &[Part::Two]);
+        assert!(
+            matches!(&report.parts[0].outcome, Outcome::Panicked(msg) if msg.starts_with("zero"))
+        );
+    }
+
+    /// Solved without input.
+    struct Constant;
+
+    impl Solution for Constant {
+        const DAY: u8 = 7;
+        const TITLE: &'static str = "Constant";
+
+        type Input = ();
+
+        fn parse(_input: &str) -> AocResult<Self::Input> {
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> AocResult<Answer> {
+            Ok(1u8.into())
+        }
+
+        fn part2(_input: &Self::Input) -> AocResult<Answer> {
+            Err(AocError::Unimplemented)
+        }
+    }
+
+    #[test]
+    fn test_run_days() {
+        install_panic_hook();
+        let puzzles: [&dyn Puzzle; 3] = [&Constant, &Dummy, &Constant];
+        let summary = run_days(&puzzles, &[Part::One], 3, |puzzle, parts| {
+            if puzzle.day() == Dummy::DAY {
+                panic!("broken day");
+            }
+            // Finish after the broken day, sleeping takes no CPU time.
+            thread::sleep(Duration::from_millis(20));
+            run_day(puzzle, "", parts)
+        });
+
+        let days = summary.reports.iter().map(|x| x.day).collect::<Vec<_>>();
+        assert_eq!(days, [7, 42, 7]);
+        assert_eq!(
+            summary.reports[0].parts[0].outcome,
+            Outcome::Solved(Answer::UInt(1))
+        );
+        assert!(
+            matches!(&summary.reports[1].parts[0].outcome, Outcome::Panicked(msg) if msg.starts_with("broken day"))
+        );
+        assert_eq!(summary.jobs, 3);
+        assert!(summary.wall >= Duration::from_millis(20));
+        if let Some(cpu) = summary.cpu {
+            assert!(cpu < Duration::from_millis(40));
+        }
+    }
+
+    #[test]
+    fn test_format_reports() {
+        install_panic_hook();
+        let report = run_day(&Dummy, "1,2", &Part::ALL);
+        let json = format_reports(std::slice::from_ref(&report), Format::Json);
+        let lines = json.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with(
+            r#"{"day":42,"title":"Dummy","part":1,"status":"ok","answer":"3","error":null,"parse_ns":"#
+        ));
+        assert!(lines[2].contains(r#""status":"todo","answer":null,"error":"not implemented""#));
+
+        let csv = format_reports(&[report], Format::Csv);
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[0],
+            "day,title,part,status,answer,error,parse_ns,solve_ns,parse_peak_bytes,allocations,alloc_bytes,peak_bytes"
+        );
+        assert!(lines[1].starts_with("42,Dummy,1,ok,3,,"));
+        assert_eq!("csv".parse::<Format>(), Ok(Format::Csv));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_csv() {
+        let rows = [[String::from("a,b"), String::from("say \"hi\"")]];
+        assert_eq!(csv(&["x", "y"], &rows), "x,y\n\"a,b\",\"say \"\"hi\"\"\"\n");
+
+        let parsed = parse_csv(&csv(&["x", "y"], &rows)).unwrap();
+        assert_eq!(parsed, [vec!["x", "y"], vec!["a,b", "say \"hi\""]]);
+        assert_eq!(
+            parse_csv("a,,\r\nb").unwrap(),
+            [vec!["a", "", ""], vec!["b"]]
+        );
+        assert!(parse_csv("\"a").is_err());
+    }
+
+    #[test]
+    fn test_format_table() {
+        let report = DayReport {
+            day: 1,
+            title: "Dummy",
+            parse_elapsed: Duration::ZERO,
+            parse_memory: None,
+            parts: vec![PartReport {
+                part: Part::One,
+                outcome: Outcome::Solved(Answer::Int(-3)),
+                elapsed: Duration::ZERO,
+                memory: None,
+            }],
+        };
+        let table = format_table(&[report]);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[2].starts_with("| 01  | Dummy | 1    | ok     | -3     |"));
+    }
+}
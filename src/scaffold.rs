@@ -0,0 +1,326 @@
+//! Create a new day, see `aoc new`.
+//!
+//! A day needs its solution in `src/days/dayNN.rs`, the registration in `src/days/mod.rs`, a
+//! binary in `bin/dayNN.rs` with its `[[bin]]` entry in `Cargo.toml`, the embedded input entry, an
+//! empty input and an example fixture. Existing files are never overwritten.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::fixtures::FIXTURES_DIR;
+use crate::input::{self, DEFAULT_DATA_DIR};
+
+/// Source of the solution of a new day.
+pub fn solution_template(day: u8, title: &str) -> String {
+    format!(
+        r#"use crate::error::{{AocError, AocResult}};
+use crate::{{Answer, Solution}};
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    const DAY: u8 = {day};
+    const TITLE: &'static str = {title:?};
+
+    type Input = String;
+
+    fn parse(input: &str) -> AocResult<Self::Input> {{
+        Ok(input.to_owned())
+    }}
+
+    fn part1(_input: &Self::Input) -> AocResult<Answer> {{
+        Err(AocError::Unimplemented)
+    }}
+
+    fn part2(_input: &Self::Input) -> AocResult<Answer> {{
+        Err(AocError::Unimplemented)
+    }}
+}}
+"#
+    )
+}
+
+/// Source of the binary of a new day.
+pub fn bin_template(day: u8) -> String {
+    format!(
+        "use aoc2024::{{days::day{day:02}::Day{day:02}, input}};\n\n\
+         fn main() {{\n    input::run::<Day{day:02}>();\n}}\n"
+    )
+}
+
+/// Example fixture without expected answers, so that no test is generated until they are filled.
+pub fn fixture_template() -> String {
+    String::from("# Fill in expected answers of the example, e.g. `part1 = 42`.\n---\n")
+}
+
+/// An entry of a list sorted by day, spans lines `start..=end`.
+struct Entry {
+    day: u8,
+    start: usize,
+    end: usize,
+}
+
+/// Insert `new` into `text` keeping entries sorted by day.
+///
+/// `new` is inserted before the first entry of a later day, or after the last entry.
+fn insert_entry(
+    text: &str,
+    day: u8,
+    entries: Vec<Entry>,
+    new: &[String],
+    what: &str,
+) -> Result<String, String> {
+    if entries.iter().any(|x| x.day == day) {
+        return Err(format!("day {day} is already in {what}"));
+    }
+    let mut lines = text.lines().map(String::from).collect::<Vec<_>>();
+    let at = match entries.iter().find(|x| x.day > day) {
+        Some(next) => next.start,
+        None => match entries.last() {
+            Some(last) => last.end + 1,
+            None => return Err(format!("no day found in {what}")),
+        },
+    };
+    lines.splice(at..at, new.iter().cloned());
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Single line entries, `key_of` returns the day of a line.
+fn line_entries(text: &str, key_of: impl Fn(&str) -> Option<u8>) -> Vec<Entry> {
+    text.lines()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            key_of(line.trim()).map(|day| Entry {
+                day,
+                start: idx,
+                end: idx,
+            })
+        })
+        .collect()
+}
+
+fn day_number(s: &str) -> Option<u8> {
+    if s.len() == 2 && s.bytes().all(|x| x.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Add `pub mod dayNN;` and the registry entry to `src/days/mod.rs`.
+pub fn register_day(days_mod: &str, day: u8) -> Result<String, String> {
+    let entries = line_entries(days_mod, |line| {
+        day_number(line.strip_prefix("pub mod day")?.strip_suffix(';')?)
+    });
+    let text = insert_entry(
+        days_mod,
+        day,
+        entries,
+        &[format!("pub mod day{day:02};")],
+        "the module list",
+    )?;
+
+    let entries = line_entries(&text, |line| {
+        let rest = line.strip_prefix("&day")?;
+        day_number(rest.get(..2)?).filter(|_| rest.ends_with(','))
+    });
+    insert_entry(
+        &text,
+        day,
+        entries,
+        &[format!("    &day{day:02}::Day{day:02},")],
+        "the registry",
+    )
+}
+
+/// Add the `[[bin]]` entry to `Cargo.toml`.
+pub fn add_bin_entry(cargo_toml: &str, day: u8) -> Result<String, String> {
+    let lines = cargo_toml.lines().collect::<Vec<_>>();
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.trim() == "[[bin]]")
+        .filter_map(|(start, _)| {
+            // A block ends before the next empty line or section.
+            let len = lines[start + 1..]
+                .iter()
+                .take_while(|x| !x.trim().is_empty() && !x.starts_with('['))
+                .count();
+            let end = start + len;
+            let day = lines[start..=end].iter().find_map(|x| {
+                let path = x.trim().strip_prefix("path = \"bin/day")?;
+                day_number(path.strip_suffix(".rs\"")?)
+            })?;
+            Some(Entry { day, start, end })
+        })
+        .collect::<Vec<_>>();
+
+    let block = [
+        String::from("[[bin]]"),
+        format!("name = \"day{day:02}\""),
+        format!("path = \"bin/day{day:02}.rs\""),
+    ];
+    // Keep an empty line between blocks.
+    let new = if entries.iter().any(|x| x.day > day) {
+        [block.as_slice(), &[String::new()]].concat()
+    } else {
+        [&[String::new()], block.as_slice()].concat()
+    };
+    insert_entry(cargo_toml, day, entries, &new, "Cargo.toml")
+}
+
+/// Add the day to embedded inputs in `src/input.rs`.
+pub fn add_embedded_input(input_rs: &str, day: u8) -> Result<String, String> {
+    let entries = line_entries(input_rs, |line| {
+        let (day, rest) = line.split_once(" => embed!(")?;
+        rest.ends_with("),").then(|| day.parse().ok())?
+    });
+    insert_entry(
+        input_rs,
+        day,
+        entries,
+        &[format!("            {day} => embed!(\"{day:02}\"),")],
+        "embedded inputs",
+    )
+}
+
+/// Create all files of `day` under the repository `root`, returns paths created or changed.
+///
+/// Nothing is written if any generated file already exists or the day is already registered. An
+/// existing input is kept.
+pub fn create(root: &Path, day: u8, title: &str) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day {day}, expected 1 to 25"));
+    }
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|e| format!("failed to read {}: {e}", path.display()))
+    };
+
+    let cargo_toml = root.join("Cargo.toml");
+    let days_mod = root.join("src/days/mod.rs");
+    let input_rs = root.join("src/input.rs");
+    let mut changes = vec![
+        (
+            root.join(format!("src/days/day{day:02}.rs")),
+            solution_template(day, title),
+        ),
+        (root.join(format!("bin/day{day:02}.rs")), bin_template(day)),
+        (
+            root.join(FIXTURES_DIR)
+                .join(format!("{day:02}"))
+                .join("example.txt"),
+            fixture_template(),
+        ),
+    ];
+    for (path, _) in changes.iter() {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+
+    let data_dir = root.join(DEFAULT_DATA_DIR);
+    if !input::input_path(&data_dir, day, None).exists()
+        && !input::input_path(&data_dir, day, Some(1)).exists()
+    {
+        changes.push((input::input_path(&data_dir, day, None), String::new()));
+    }
+
+    changes.push((cargo_toml.clone(), add_bin_entry(&read(&cargo_toml)?, day)?));
+    changes.push((days_mod.clone(), register_day(&read(&days_mod)?, day)?));
+    changes.push((
+        input_rs.clone(),
+        add_embedded_input(&read(&input_rs)?, day)?,
+    ));
+
+    for (path, content) in changes.iter() {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("failed to create {}: {e}", dir.display()))?;
+        }
+        fs::write(path, content).map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+    }
+
+    Ok(changes.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DAYS_MOD: &str = "\
+pub mod day01;
+pub mod day03;
+
+pub static DAYS: &[&dyn Puzzle] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn test_register_day() {
+        let text = register_day(DAYS_MOD, 2).unwrap();
+        assert!(text.contains("pub mod day01;\npub mod day02;\npub mod day03;\n"));
+        assert!(text.contains("    &day01::Day01,\n    &day02::Day02,\n    &day03::Day03,\n"));
+
+        let text = register_day(DAYS_MOD, 17).unwrap();
+        assert!(text.contains("pub mod day03;\npub mod day17;\n\n"));
+        assert!(text.contains("    &day17::Day17,\n];"));
+
+        assert!(register_day(DAYS_MOD, 3).is_err());
+    }
+
+    #[test]
+    fn test_add_bin_entry() {
+        let toml = "[package]\nname = \"aoc2024\"\n\n[[bin]]\nname = \"day01\"\npath = \"bin/day01.rs\"\n\n[features]\n";
+        let text = add_bin_entry(toml, 2).unwrap();
+        assert!(text.ends_with(
+            "path = \"bin/day01.rs\"\n\n[[bin]]\nname = \"day02\"\npath = \"bin/day02.rs\"\n\n[features]\n"
+        ));
+        assert!(add_bin_entry(toml, 1).is_err());
+    }
+
+    #[test]
+    fn test_add_embedded_input() {
+        let text = "        let input = match day {\n            1 => embed!(\"01\"),\n            _ => return None,\n";
+        let text = add_embedded_input(text, 2).unwrap();
+        assert!(
+            text.contains("            1 => embed!(\"01\"),\n            2 => embed!(\"02\"),\n")
+        );
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aoc2024-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[[bin]]\nname = \"day01\"\npath = \"bin/day01.rs\"\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/days/mod.rs"), DAYS_MOD).unwrap();
+        fs::write(
+            root.join("src/input.rs"),
+            "            1 => embed!(\"01\"),\n",
+        )
+        .unwrap();
+
+        let changed = create(&root, 2, "Red-Nosed Reports").unwrap();
+        assert_eq!(changed.len(), 7);
+        let solution = fs::read_to_string(root.join("src/days/day02.rs")).unwrap();
+        assert!(solution.contains("const TITLE: &'static str = \"Red-Nosed Reports\";"));
+        assert_eq!(fs::read_to_string(root.join("data/02.txt")).unwrap(), "");
+
+        // Nothing is overwritten.
+        fs::write(root.join("src/days/day02.rs"), "// solved").unwrap();
+        assert!(create(&root, 2, "Again").is_err());
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/day02.rs")).unwrap(),
+            "// solved"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
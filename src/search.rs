@@ -0,0 +1,371 @@
+//! Graph search over arbitrary states.
+//!
+//! States are anything hashable, e.g. a point or a point with facing. The graph is given by a
+//! successor function, so it is never built explicitly:
+//!
+//! ```text
+//! // Walk on the grid, turning costs 1000.
+//! let paths = dijkstra([(start, Dir4::Right)], |&(p, dir)| {
+//!     let forward = world.offset(p, dir).map(|next| ((next, dir), 1));
+//!     let turns = [(p, dir.turn_left()), (p, dir.turn_right())].map(|x| (x, 1000));
+//!     forward.into_iter().chain(turns)
+//! });
+//! ```
+//!
+//! All searches record every optimal predecessor of a state, so every best path can be
+//! reconstructed from the [`Paths`] they return. Zero cost edges back into an ancestor are left
+//! out, so best paths never go around a zero cost cycle.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Distances from the start states and predecessors on best paths.
+#[derive(Debug, Clone)]
+pub struct Paths<S> {
+    dist: HashMap<S, usize>,
+    preds: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> Paths<S> {
+    fn new() -> Self {
+        Self {
+            dist: HashMap::new(),
+            preds: HashMap::new(),
+        }
+    }
+
+    /// Cost of the best path to `state`, `None` if not reached.
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.dist.get(state).copied()
+    }
+
+    /// All reached states with their distances.
+    pub fn distances(&self) -> &HashMap<S, usize> {
+        &self.dist
+    }
+
+    /// Previous states of `state` on all best paths, empty for start states.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.preds.get(state).map_or(&[], |x| x.as_slice())
+    }
+
+    /// One of the best paths to `target`, from a start state to `target` inclusive.
+    pub fn path(&self, target: &S) -> Option<Vec<S>> {
+        self.dist.get(target)?;
+        let mut path = vec![target.clone()];
+        while let Some(prev) = self.predecessors(path.last().unwrap()).first() {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All best paths to `target`.
+    ///
+    /// The count grows exponentially on some graphs, use [`Paths::count_paths`] or
+    /// [`Paths::on_best_paths`] if the paths themselves are not needed.
+    pub fn all_paths(&self, target: &S) -> Vec<Vec<S>> {
+        if !self.dist.contains_key(target) {
+            return vec![];
+        }
+        let preds = self.predecessors(target);
+        if preds.is_empty() {
+            return vec![vec![target.clone()]];
+        }
+        preds
+            .iter()
+            .flat_map(|prev| self.all_paths(prev))
+            .map(|mut path| {
+                path.push(target.clone());
+                path
+            })
+            .collect()
+    }
+
+    /// Number of best paths to `target`.
+    pub fn count_paths(&self, target: &S) -> usize {
+        fn count<S: Clone + Eq + Hash>(
+            paths: &Paths<S>,
+            state: &S,
+            memo: &mut HashMap<S, usize>,
+        ) -> usize {
+            if let Some(n) = memo.get(state) {
+                return *n;
+            }
+            let preds = paths.predecessors(state);
+            let n = if preds.is_empty() {
+                1
+            } else {
+                preds.iter().map(|prev| count(paths, prev, memo)).sum()
+            };
+            memo.insert(state.clone(), n);
+            n
+        }
+
+        if !self.dist.contains_key(target) {
+            return 0;
+        }
+        count(self, target, &mut HashMap::new())
+    }
+
+    /// States on any best path to any of `targets`.
+    pub fn on_best_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack = targets
+            .into_iter()
+            .filter(|x| self.dist.contains_key(x))
+            .collect::<Vec<_>>();
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        seen
+    }
+
+    /// Record the edge `from -> to` with the cost of path through it, returns whether `to` is
+    /// improved.
+    fn relax(&mut self, from: &S, to: S, cost: usize) -> bool {
+        match self.dist.get(&to) {
+            Some(&old) if cost > old => false,
+            // Only start states are reached without a predecessor, they keep none, so that a path
+            // ends there even on zero cost cycles.
+            Some(_) if !self.preds.contains_key(&to) => false,
+            // A zero cost edge into an ancestor of `from` would make the predecessors a cycle.
+            Some(&old)
+                if cost == old
+                    && self.dist.get(from) == Some(&cost)
+                    && self.on_best_paths([from.clone()]).contains(&to) =>
+            {
+                false
+            }
+            Some(&old) if cost == old => {
+                let preds = self.preds.entry(to).or_default();
+                if !preds.contains(from) {
+                    preds.push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.preds.insert(to.clone(), vec![from.clone()]);
+                self.dist.insert(to, cost);
+                true
+            }
+        }
+    }
+
+    fn add_start(&mut self, state: S) -> bool {
+        if self.dist.contains_key(&state) {
+            return false;
+        }
+        self.dist.insert(state, 0);
+        true
+    }
+}
+
+/// Breadth first search, every step costs 1.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if paths.add_start(start.clone()) {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let cost = paths.dist[&state] + 1;
+        for next in successors(&state) {
+            if paths.relax(&state, next.clone(), cost) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Shortest paths with non-negative step costs.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(starts, successors, |_| 0, |_| false)
+}
+
+/// Shortest paths to the goal, guided by `heuristic`.
+///
+/// `heuristic` estimates the remaining cost to the goal, it must never overestimate and must
+/// be consistent for the result to be optimal. The search stops once no state can be on a best
+/// path to a goal, so all best paths to the goals are kept. States far from the goal may not be
+/// reached.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Paths<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut paths = Paths::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if paths.add_start(start.clone()) {
+            heap.push(Entry {
+                estimate: heuristic(&start),
+                cost: 0,
+                state: start,
+            });
+        }
+    }
+
+    let mut done = HashSet::new();
+    let mut best_goal = None;
+    while let Some(Entry {
+        estimate,
+        cost,
+        state,
+    }) = heap.pop()
+    {
+        if best_goal.is_some_and(|best| estimate > best) {
+            break;
+        }
+        if cost > paths.dist[&state] || !done.insert(state.clone()) {
+            continue;
+        }
+        if is_goal(&state) {
+            best_goal.get_or_insert(cost);
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if paths.relax(&state, next.clone(), next_cost) {
+                heap.push(Entry {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    paths
+}
+
+/// State in the heap, ordered by the estimated total cost only, cheapest first.
+struct Entry<S> {
+    estimate: usize,
+    cost: usize,
+    state: S,
+}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S> Eq for Entry<S> {}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Diamond: 0 -> 1 -> 3 and 0 -> 2 -> 3, plus a costly shortcut 0 -> 3.
+    fn diamond(state: &u8) -> Vec<(u8, usize)> {
+        match state {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 | 2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let paths = bfs([0u8], |x| diamond(x).into_iter().map(|(s, _)| s));
+        assert_eq!(paths.distance(&3), Some(1));
+        assert_eq!(paths.predecessors(&3), [0]);
+        assert_eq!(paths.path(&3), Some(vec![0, 3]));
+        assert_eq!(paths.distance(&4), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra([0u8], diamond);
+        assert_eq!(paths.distance(&3), Some(2));
+        let mut preds = paths.predecessors(&3).to_vec();
+        preds.sort();
+        assert_eq!(preds, [1, 2]);
+        assert_eq!(paths.count_paths(&3), 2);
+        assert_eq!(paths.all_paths(&3).len(), 2);
+        assert_eq!(paths.on_best_paths([3]).len(), 4);
+    }
+
+    #[test]
+    fn test_zero_cost_cycle() {
+        let paths = dijkstra([0u8], |x| [((x + 1) % 3, 0)]);
+        assert!(paths.predecessors(&0).is_empty());
+        assert_eq!(paths.path(&2), Some(vec![0, 1, 2]));
+
+        // The cycle 1 -> 2 -> 1 does not pass the start.
+        let paths = dijkstra([0u8], |x| match x {
+            0 => vec![(1, 0)],
+            1 => vec![(2, 0)],
+            2 => vec![(1, 0)],
+            _ => vec![],
+        });
+        assert_eq!(paths.count_paths(&2), 1);
+        assert_eq!(paths.all_paths(&2), [vec![0, 1, 2]]);
+        assert_eq!(paths.count_paths(&1), 1);
+
+        // Both zero cost ways are kept when they do not form a cycle.
+        let paths = dijkstra([0u8], |x| match x {
+            0 => vec![(1, 0), (2, 0)],
+            1 | 2 => vec![(3, 0)],
+            _ => vec![],
+        });
+        assert_eq!(paths.count_paths(&3), 2);
+    }
+
+    #[test]
+    fn test_astar() {
+        // Walk on a line, the goal is 10.
+        let line = |x: &i32| [(x - 1, 1), (x + 1, 1)];
+        let paths = astar(
+            [0],
+            line,
+            |x| (10 - x).unsigned_abs() as usize,
+            |x| *x == 10,
+        );
+        assert_eq!(paths.distance(&10), Some(10));
+        assert_eq!(paths.path(&10).unwrap().len(), 11);
+        // The heuristic keeps the search from walking far away.
+        assert_eq!(paths.distance(&-5), None);
+    }
+}
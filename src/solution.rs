@@ -0,0 +1,213 @@
+use std::any::Any;
+use std::fmt;
+use std::ops::Range;
+
+use crate::error::{AocError, AocResult};
+use crate::grid::Grid;
+use crate::rng::Rng;
+
+/// The two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// 1-based index of the part.
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "PART {}", self.number())
+    }
+}
+
+/// Answer of a single part.
+///
+/// Solvers produce different kinds of numbers, convert them into [`Answer`] with `into()` so that
+/// all days can be printed and compared the same way.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    UInt(u128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(v) => write!(f, "{v}"),
+            Answer::UInt(v) => write!(f, "{v}"),
+            Answer::Text(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+answer_from!(Int, i128, i8, i16, i32, i64, i128, isize);
+answer_from!(UInt, u128, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+/// A solution of one day.
+///
+/// The raw input is parsed once by [`Solution::parse`] and the result is shared by both parts.
+/// Malformed input and unsolvable states are reported as [`AocError`](crate::error::AocError)
+/// instead of panics.
+pub trait Solution {
+    /// Day of the puzzle, 1-based.
+    const DAY: u8;
+
+    /// Title of the puzzle.
+    const TITLE: &'static str;
+
+    /// Parsed puzzle input.
+    type Input;
+
+    fn parse(input: &str) -> AocResult<Self::Input>;
+
+    fn part1(input: &Self::Input) -> AocResult<Answer>;
+
+    fn part2(input: &Self::Input) -> AocResult<Answer>;
+
+    /// Pass the map of the simulation after each of `steps` to `frame`, steps stop early if the
+    /// simulation ends or `frame` returns `false`.
+    ///
+    /// Days without a simulation keep the default, which returns [`AocError::Unimplemented`].
+    fn frames(
+        _input: &Self::Input,
+        _steps: Range<usize>,
+        _frame: &mut dyn FnMut(usize, &Grid<char>) -> bool,
+    ) -> AocResult<()> {
+        Err(AocError::Unimplemented)
+    }
+
+    /// Random valid input drawn from `rng`, `size` scales it, e.g. the number of lines or the
+    /// side of a map.
+    ///
+    /// Days without a generator keep the default, which returns [`AocError::Unimplemented`].
+    fn generate(_rng: &mut Rng, _size: usize) -> AocResult<String> {
+        Err(AocError::Unimplemented)
+    }
+
+    /// Solve both parts and print the answers.
+    fn run(input: &str) -> AocResult<()> {
+        let input = Self::parse(input)?;
+        println!("{}: {}", Part::One, Self::part1(&input)?);
+        println!("{}: {}", Part::Two, Self::part2(&input)?);
+        Ok(())
+    }
+}
+
+/// Type erased [`Solution`], this is what the registry holds.
+///
+/// Every [`Solution`] implements it, parsed input is passed around as [`Any`].
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    fn parse(&self, input: &str) -> AocResult<Box<dyn Any>>;
+
+    /// Solve `part` with the input produced by [`Puzzle::parse`] of the same puzzle.
+    fn solve(&self, part: Part, input: &dyn Any) -> AocResult<Answer>;
+
+    /// See [`Solution::frames`].
+    fn frames(
+        &self,
+        input: &dyn Any,
+        steps: Range<usize>,
+        frame: &mut dyn FnMut(usize, &Grid<char>) -> bool,
+    ) -> AocResult<()>;
+
+    /// See [`Solution::generate`].
+    fn generate(&self, rng: &mut Rng, size: usize) -> AocResult<String>;
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn parse(&self, input: &str) -> AocResult<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, part: Part, input: &dyn Any) -> AocResult<Answer> {
+        let input = downcast::<S>(input)?;
+        match part {
+            Part::One => S::part1(input),
+            Part::Two => S::part2(input),
+        }
+    }
+
+    fn frames(
+        &self,
+        input: &dyn Any,
+        steps: Range<usize>,
+        frame: &mut dyn FnMut(usize, &Grid<char>) -> bool,
+    ) -> AocResult<()> {
+        S::frames(downcast::<S>(input)?, steps, frame)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> AocResult<String> {
+        S::generate(rng, size)
+    }
+}
+
+/// Input parsed by [`Puzzle::parse`] of `S`.
+fn downcast<S>(input: &dyn Any) -> AocResult<&S::Input>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    input.downcast_ref::<S::Input>().ok_or_else(|| {
+        AocError::invalid(format!("input of day {} is not parsed by itself", S::DAY))
+    })
+}
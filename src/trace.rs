@@ -0,0 +1,192 @@
+//! Leveled debug output on stderr, filtered by `AOC_LOG`.
+//!
+//! `AOC_LOG` is a comma separated list of a default level and `<module>=<level>` filters, the
+//! longest matching module wins. A module is matched by any `::` separated part of the module
+//! path, so a day is simply its module name:
+//!
+//! ```text
+//! AOC_LOG=debug                   debug output of everything
+//! AOC_LOG=day13=trace             trace output of day 13 only
+//! AOC_LOG=info,day09=off,grid=debug
+//! ```
+//!
+//! Levels are `off`, `error`, `warn`, `info`, `debug` and `trace`. Nothing is written if `AOC_LOG`
+//! is not set.
+//!
+//! ```text
+//! crate::debug!("cost of {x} {y} is {cost}");
+//! ```
+//!
+//! Tracing is compiled out of release builds unless the `trace` feature is enabled, arguments
+//! are not evaluated then.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Environment variable of the filter.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// Whether tracing is compiled in.
+pub const ENABLED: bool = cfg!(any(debug_assertions, feature = "trace"));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+}
+
+/// Most verbose level written, `None` is off.
+fn parse_level(s: &str) -> Result<Option<Level>, String> {
+    match s.trim().to_ascii_lowercase().as_str() {
+        "off" => Ok(None),
+        "error" => Ok(Some(Level::Error)),
+        "warn" => Ok(Some(Level::Warn)),
+        "info" => Ok(Some(Level::Info)),
+        "debug" => Ok(Some(Level::Debug)),
+        "trace" => Ok(Some(Level::Trace)),
+        _ => Err(format!("unknown log level {s}")),
+    }
+}
+
+/// Parsed `AOC_LOG`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    modules: Vec<(String, Option<Level>)>,
+}
+
+impl Filter {
+    /// Whether output of `level` in module `path` is written.
+    pub fn enabled(&self, level: Level, path: &str) -> bool {
+        let max = self
+            .modules
+            .iter()
+            .filter(|(module, _)| matches_module(module, path))
+            .max_by_key(|(module, _)| module.len())
+            .map_or(self.default, |(_, level)| *level);
+        max.is_some_and(|max| level <= max)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Self::default();
+        for directive in s.split(',').map(str::trim).filter(|x| !x.is_empty()) {
+            match directive.split_once('=') {
+                Some((module, level)) => filter
+                    .modules
+                    .push((module.trim().to_owned(), parse_level(level)?)),
+                None => filter.default = parse_level(directive)?,
+            }
+        }
+        Ok(filter)
+    }
+}
+
+/// Whether `module` is a run of whole parts of `path`.
+fn matches_module(module: &str, path: &str) -> bool {
+    path == module
+        || path.starts_with(&format!("{module}::"))
+        || path.ends_with(&format!("::{module}"))
+        || path.contains(&format!("::{module}::"))
+}
+
+/// The filter of `AOC_LOG`, read once.
+pub fn filter() -> &'static Filter {
+    static FILTER: OnceLock<Filter> = OnceLock::new();
+    FILTER.get_or_init(|| match std::env::var(LOG_ENV) {
+        Ok(spec) => spec.parse().unwrap_or_else(|e| {
+            eprintln!("{LOG_ENV}: {e}");
+            Filter::default()
+        }),
+        Err(_) => Filter::default(),
+    })
+}
+
+/// Used by the macros, `path` is the module path of the caller.
+pub fn enabled(level: Level, path: &str) -> bool {
+    ENABLED && filter().enabled(level, path)
+}
+
+/// Used by the macros, writes a line to stderr.
+pub fn write(level: Level, path: &str, args: fmt::Arguments<'_>) {
+    let module = path
+        .trim_start_matches("aoc2024::")
+        .trim_start_matches("days::");
+    eprintln!("[{:<5} {module}] {args}", level.name());
+}
+
+/// Write at `level` if enabled for the calling module, see the [module docs](self).
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::trace::ENABLED && $crate::trace::enabled($level, module_path!()) {
+            $crate::trace::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::trace::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_filter() {
+        let filter = "info,day09=off,days::day13=trace,grid=debug"
+            .parse::<Filter>()
+            .unwrap();
+        assert!(filter.enabled(Level::Info, "aoc2024::runner"));
+        assert!(!filter.enabled(Level::Debug, "aoc2024::runner"));
+        assert!(!filter.enabled(Level::Error, "aoc2024::days::day09"));
+        assert!(filter.enabled(Level::Trace, "aoc2024::days::day13"));
+        assert!(filter.enabled(Level::Trace, "aoc2024::days::day13::test"));
+        assert!(filter.enabled(Level::Debug, "aoc2024::grid"));
+        assert!(!filter.enabled(Level::Debug, "aoc2024::gridlike"));
+
+        assert!(!Filter::default().enabled(Level::Error, "aoc2024"));
+        assert!("day13=loud".parse::<Filter>().is_err());
+    }
+}
@@ -0,0 +1,210 @@
+//! Watch the files of a day, to rerun it whenever one changes.
+//!
+//! Modification times are polled, std has no file notifications:
+//!
+//! ```text
+//! let mut snapshot = Snapshot::take(&day_paths(root, &data_dir, 6));
+//! loop {
+//!     thread::sleep(interval);
+//!     let next = Snapshot::take(&day_paths(root, &data_dir, 6));
+//!     let changed = snapshot.changed(&next);
+//!     ...
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::fixtures::{self, FIXTURES_DIR};
+use crate::runner;
+use crate::Part;
+
+/// Source, binary, inputs and fixtures of `day` in the repository at `root`.
+///
+/// Paths are listed again on every poll, so that new inputs and fixtures are noticed.
+pub fn day_paths(root: &Path, data_dir: &Path, day: u8) -> Vec<PathBuf> {
+    let mut paths = vec![
+        root.join(format!("src/days/day{day:02}.rs")),
+        root.join(format!("bin/day{day:02}.rs")),
+    ];
+
+    // `NN.txt`, or split inputs `NN_01.txt`, `NN_02.txt`, ...
+    let whole = format!("{day:02}.txt");
+    let split = format!("{day:02}_");
+    if let Ok(entries) = std::fs::read_dir(data_dir) {
+        let mut inputs = entries
+            .filter_map(|x| x.ok())
+            .map(|x| x.path())
+            .filter(|x| {
+                x.file_name()
+                    .and_then(|x| x.to_str())
+                    .is_some_and(|x| x == whole || x.starts_with(&split) && x.ends_with(".txt"))
+            })
+            .collect::<Vec<_>>();
+        inputs.sort();
+        paths.extend(inputs);
+    }
+
+    paths.extend(fixtures::fixture_paths(&root.join(FIXTURES_DIR), day));
+    paths
+}
+
+/// Modification times of files, `None` if missing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    mtimes: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Self {
+        let mtimes = paths
+            .iter()
+            .map(|path| {
+                let mtime = std::fs::metadata(path).and_then(|x| x.modified()).ok();
+                (path.clone(), mtime)
+            })
+            .collect();
+        Self { mtimes }
+    }
+
+    /// Paths modified, created or removed in `next`.
+    pub fn changed(&self, next: &Snapshot) -> Vec<PathBuf> {
+        let mut changed = next
+            .mtimes
+            .iter()
+            .filter(|(path, mtime)| self.mtimes.get(*path) != Some(mtime))
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        changed.extend(
+            self.mtimes
+                .keys()
+                .filter(|path| !next.mtimes.contains_key(*path))
+                .cloned(),
+        );
+        changed
+    }
+
+    pub fn len(&self) -> usize {
+        self.mtimes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mtimes.is_empty()
+    }
+}
+
+/// Answers of parts in CSV output of a run, or the status and error of parts not solved.
+pub fn parse_answers(csv: &str) -> Result<BTreeMap<Part, String>, String> {
+    let records = runner::parse_csv(csv)?;
+    let (header, rows) = records.split_first().ok_or("empty output")?;
+    let column = |name| {
+        header
+            .iter()
+            .position(|x| x == name)
+            .ok_or_else(|| format!("no {name} column"))
+    };
+    let [part, status, answer, error] = [
+        column("part")?,
+        column("status")?,
+        column("answer")?,
+        column("error")?,
+    ];
+
+    let mut answers = BTreeMap::new();
+    for row in rows {
+        let field = |idx: usize| row.get(idx).map(String::as_str).unwrap_or("");
+        let Some(number) = field(part).parse::<u8>().ok().and_then(Part::from_number) else {
+            return Err(format!("invalid part {:?}", field(part)));
+        };
+        let value = match field(status) {
+            "ok" => field(answer).to_owned(),
+            v => format!("{v}: {}", field(error)),
+        };
+        answers.insert(number, value);
+    }
+    Ok(answers)
+}
+
+/// One line per part, what the answer is and how it changed since `previous`.
+pub fn diff_answers(
+    previous: Option<&BTreeMap<Part, String>>,
+    current: &BTreeMap<Part, String>,
+) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, answer)| {
+            let change = match previous.and_then(|x| x.get(part)) {
+                None => String::new(),
+                Some(v) if v == answer => String::from(" (same)"),
+                Some(v) => format!(" (was {v})"),
+            };
+            format!("{part}: {answer}{change}")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_snapshot() {
+        let root = std::env::temp_dir().join(format!("aoc2024-watch-{}", std::process::id()));
+        let data = root.join("data");
+        std::fs::create_dir_all(root.join("fixtures/06")).unwrap();
+        std::fs::create_dir_all(&data).unwrap();
+        std::fs::write(data.join("06_01.txt"), "a").unwrap();
+        std::fs::write(data.join("16.txt"), "b").unwrap();
+        std::fs::write(root.join("fixtures/06/example.txt"), "---\n").unwrap();
+
+        let paths = day_paths(&root, &data, 6);
+        assert_eq!(
+            paths,
+            [
+                root.join("src/days/day06.rs"),
+                root.join("bin/day06.rs"),
+                data.join("06_01.txt"),
+                root.join("fixtures/06/example.txt"),
+            ]
+        );
+
+        let before = Snapshot::take(&paths);
+        assert_eq!(before.len(), 4);
+        assert!(before.changed(&Snapshot::take(&paths)).is_empty());
+
+        std::fs::create_dir_all(root.join("src/days")).unwrap();
+        std::fs::write(root.join("src/days/day06.rs"), "").unwrap();
+        std::fs::remove_file(data.join("06_01.txt")).unwrap();
+        let after = Snapshot::take(&day_paths(&root, &data, 6));
+        assert_eq!(
+            before.changed(&after),
+            [root.join("src/days/day06.rs"), data.join("06_01.txt")]
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let csv = "day,part,status,answer,error\n6,1,ok,41,\n6,2,error,,\"invalid state: no start, at all\"\n";
+        let first = parse_answers(csv).unwrap();
+        assert_eq!(
+            diff_answers(None, &first),
+            [
+                "PART 1: 41",
+                "PART 2: error: invalid state: no start, at all"
+            ]
+        );
+
+        let second = parse_answers("part,status,answer,error\n1,ok,41,\n2,ok,6,\n").unwrap();
+        assert_eq!(
+            diff_answers(Some(&first), &second),
+            [
+                "PART 1: 41 (same)",
+                "PART 2: 6 (was error: invalid state: no start, at all)"
+            ]
+        );
+        assert!(parse_answers("").is_err());
+        assert!(parse_answers("day,part\n6,1\n").is_err());
+    }
+}
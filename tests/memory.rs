@@ -0,0 +1,68 @@
+//! Heap accounting with [`CountingAlloc`] installed, which the lib tests do not have.
+
+use aoc2024::error::AocResult;
+use aoc2024::memory::{self, CountingAlloc};
+use aoc2024::runner::{self, Outcome};
+use aoc2024::{Answer, Part, Solution};
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+#[test]
+fn test_measure() {
+    let (_, usage) = memory::measure(|| {
+        let (_, inner) = memory::measure(|| drop(vec![0u8; 4096]));
+        assert_eq!(inner.unwrap().peak, 4096);
+        let kept = vec![0u64; 16];
+        std::hint::black_box(&kept);
+    });
+    let usage = usage.unwrap();
+    assert_eq!(usage.allocations, 2);
+    assert_eq!(usage.bytes, 4096 + 128);
+    assert_eq!(usage.peak, 4096);
+}
+
+/// Part 1 holds 64 KiB, part 2 gives up at its budget check with as much.
+struct Hungry;
+
+impl Solution for Hungry {
+    const DAY: u8 = 42;
+    const TITLE: &'static str = "Hungry";
+
+    type Input = ();
+
+    fn parse(_input: &str) -> AocResult<Self::Input> {
+        Ok(())
+    }
+
+    fn part1(_input: &Self::Input) -> AocResult<Answer> {
+        let held = vec![0u8; 64 << 10];
+        Ok(std::hint::black_box(held).len().into())
+    }
+
+    fn part2(_input: &Self::Input) -> AocResult<Answer> {
+        let held = vec![0u8; 64 << 10];
+        memory::check_budget()?;
+        std::hint::black_box(held);
+        Err(aoc2024::error::AocError::invalid(
+            "not stopped by the budget",
+        ))
+    }
+}
+
+#[test]
+fn test_budget() {
+    memory::set_budget(Some(16 << 10));
+    let report = runner::run_day(&Hungry, "", &Part::ALL);
+    memory::set_budget(None);
+
+    for part in &report.parts {
+        assert_eq!(
+            part.outcome,
+            Outcome::Failed(String::from(
+                "memory budget exceeded: peak 64.00 KiB over 16.00 KiB"
+            ))
+        );
+        assert!(part.memory.unwrap().peak >= 64 << 10);
+    }
+}